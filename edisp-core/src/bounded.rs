@@ -0,0 +1,242 @@
+//! Dispatching in bounded containers.
+//!
+//! Embedded and backpressure-sensitive code needs deterministic memory use:
+//! containers have a fixed capacity, and what happens when it is reached must
+//! be explicit. This module contains the `FallibleExtend` trait, which
+//! describes containers refusing values once full, and the `DispatchBounded`
+//! trait, which either fails or drops values when that happens.
+
+use core::convert::Infallible;
+use core::fmt;
+use core::iter;
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use crate::{dispatch::Variants, either::*, try_dispatch::TryExtend};
+
+/// A container which may be full.
+///
+/// Unlike `TryExtend`, a value which can not be added is given back to the
+/// caller. This trait is implemented for every container implementing
+/// `TryExtend` whose error implements `IntoRefused`, such as the growable
+/// collections of `std`, which are never full, and the fixed-capacity
+/// containers whose error is `Overflow`. Bounded containers should therefore
+/// implement `TryExtend` rather than this trait.
+pub trait FallibleExtend<T> {
+    /// Adds a value to the container.
+    ///
+    /// # Errors
+    ///
+    /// This function gives `value` back if the container is full.
+    fn try_push(&mut self, value: T) -> Result<(), T>;
+}
+
+/// An error from which the value which could not be added to a container can
+/// be recovered.
+///
+/// This trait is implemented for `Infallible`, which is the error of the
+/// containers which are never full, and for `Overflow`.
+pub trait IntoRefused<T> {
+    /// Returns the value which could not be added.
+    fn into_refused(self) -> T;
+}
+
+impl<T> IntoRefused<T> for Infallible {
+    fn into_refused(self) -> T {
+        match self {}
+    }
+}
+
+impl<T> IntoRefused<T> for Overflow<T> {
+    fn into_refused(self) -> T {
+        self.item
+    }
+}
+
+impl<T, C> FallibleExtend<T> for C
+where
+    C: TryExtend<T>,
+    C::Error: IntoRefused<T>,
+{
+    fn try_push(&mut self, value: T) -> Result<(), T> {
+        self.try_extend(iter::once(value))
+            .map_err(IntoRefused::into_refused)
+    }
+}
+
+/// What to do when a value is dispatched in a full container.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OverflowPolicy {
+    /// Stops dispatching, and returns the value in an `Overflow` error.
+    Fail,
+    /// Drops the value, and continues dispatching.
+    Drop,
+}
+
+/// The error returned when a value is dispatched in a full container.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Overflow<E> {
+    /// The value which could not be added.
+    pub item: E,
+}
+
+impl<E> fmt::Display for Overflow<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a value was dispatched in a full container")
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: fmt::Debug> Error for Overflow<E> {}
+
+/// A dispatcher trait for bounded containers.
+///
+/// The generic type `O` should be a tuple whose arity is equal to the number
+/// of variants of the implementor, and should contain only types which
+/// implement `Default` and `FallibleExtend`. When a container is full, the
+/// `OverflowPolicy` decides whether dispatching fails or whether the value is
+/// dropped.
+///
+/// ```
+/// use edisp_core::prelude::*;
+///
+/// /// A buffer holding at most one value.
+/// #[derive(Default)]
+/// struct Slot<T>(Option<T>);
+///
+/// impl<T> TryExtend<T> for Slot<T> {
+///     type Error = Overflow<T>;
+///
+///     fn try_extend<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<(), Overflow<T>> {
+///         for item in iter {
+///             if self.0.is_some() {
+///                 return Err(Overflow { item });
+///             }
+///
+///             self.0 = Some(item);
+///         }
+///
+///         Ok(())
+///     }
+/// }
+///
+/// let values = vec![Ok(1), Err("foo"), Ok(2)];
+///
+/// let (one, errs): (Slot<_>, Vec<_>) =
+///     Result::dispatch_bounded(values.clone(), OverflowPolicy::Drop).unwrap();
+/// assert_eq!((one.0, errs), (Some(1), vec!["foo"]));
+///
+/// let overflow: Result<(Slot<_>, Vec<_>), _> =
+///     Result::dispatch_bounded(values, OverflowPolicy::Fail);
+/// assert_eq!(overflow.err().unwrap().item, Ok(2));
+/// ```
+pub trait DispatchBounded<O>
+where
+    Self: Sized,
+{
+    /// Performs dispatching in bounded containers.
+    ///
+    /// # Errors
+    ///
+    /// With `OverflowPolicy::Fail`, this function returns the first value
+    /// which can not be added to its container. The iterator is not consumed
+    /// any further.
+    fn dispatch_bounded<I: IntoIterator<Item = Self>>(
+        iter: I,
+        policy: OverflowPolicy,
+    ) -> Result<O, Overflow<Self>>;
+}
+
+/// Implements `DispatchBounded` for every enum with a given number of
+/// variants.
+macro_rules! implement_dispatch_bounded_for_arity {
+    (
+        $either:ident,
+        $arity:literal,
+        $( (
+            $variant:ident,
+            $t:ident,
+            $c:ident,
+            $c_name:ident,
+            $f:ident,
+            $f_name:ident,
+            $idx:tt
+        ) ),+ $( , )?
+    ) => {
+        impl<E, $( $t, )+ $( $c, )+> DispatchBounded<( $( $c, )+ )> for E
+        where
+            E: Variants<Either = $either< $( $t, )+ >>,
+            $( $c: Default + FallibleExtend<$t>, )+
+        {
+            fn dispatch_bounded<I: IntoIterator<Item = Self>>(
+                iter: I,
+                policy: OverflowPolicy,
+            ) -> Result<( $( $c, )+ ), Overflow<Self>> {
+                $( let mut $c_name = $c::default(); )+
+
+                for element in iter {
+                    let refused = match element.into_either() {
+                        $(
+                            $either::$variant(value) => $c_name
+                                .try_push(value)
+                                .map_err($either::$variant),
+                        )+
+                    };
+
+                    if let (Err(either), OverflowPolicy::Fail) = (refused, policy) {
+                        return Err(Overflow { item: E::from_either(either) });
+                    }
+                }
+
+                Ok(( $( $c_name, )+ ))
+            }
+        }
+    };
+}
+
+for_each_arity!(implement_dispatch_bounded_for_arity);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A container which refuses values once it holds two of them.
+    #[derive(Debug, Default, PartialEq)]
+    struct Pair<T>(Vec<T>);
+
+    impl<T> TryExtend<T> for Pair<T> {
+        type Error = Overflow<T>;
+
+        fn try_extend<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<(), Overflow<T>> {
+            for item in iter {
+                if self.0.len() == 2 {
+                    return Err(Overflow { item });
+                }
+
+                self.0.push(item);
+            }
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn dispatch_bounded_drops_overflow() {
+        let i = vec![Ok(1), Ok(2), Ok(3), Err("foo"), Ok(4)].into_iter();
+        let (some_oks, some_errs): (Pair<_>, Vec<_>) =
+            Result::dispatch_bounded(i, OverflowPolicy::Drop).unwrap();
+
+        assert_eq!(some_oks, Pair(vec![1, 2]));
+        assert_eq!(some_errs, ["foo"]);
+    }
+
+    #[test]
+    fn dispatch_bounded_fails_on_overflow() {
+        let mut i = vec![Err('a'), Err('b'), Ok(1), Err('c'), Err('d')].into_iter();
+        let dispatched: Result<(Vec<_>, Pair<_>), _> =
+            Result::dispatch_bounded(i.by_ref(), OverflowPolicy::Fail);
+
+        assert_eq!(dispatched.unwrap_err(), Overflow { item: Err('c') });
+        assert_eq!(i.next(), Some(Err('d')));
+    }
+}
@@ -0,0 +1,151 @@
+//! Dispatching with an error budget.
+//!
+//! Validation pipelines do not need to accumulate millions of errors: once
+//! too many of them are met, the input can be rejected. This module contains
+//! the `DispatchWithBudget` trait, which stops dispatching once a designated
+//! container holds too many values.
+
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use crate::{dispatch::Variants, either::*};
+
+/// The error returned when the error budget is exceeded.
+///
+/// It holds the containers as they were when dispatching stopped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BudgetExceeded<O> {
+    /// The containers filled before dispatching stopped.
+    pub partial: O,
+}
+
+impl<O> fmt::Display for BudgetExceeded<O> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "the error budget was exceeded")
+    }
+}
+
+#[cfg(feature = "std")]
+impl<O: fmt::Debug> Error for BudgetExceeded<O> {}
+
+/// A dispatcher trait stopping once an error budget is exceeded.
+///
+/// This trait behaves like `Dispatch`, except that dispatching stops as soon
+/// as the container of the variant whose zero-based index is `failure` has
+/// received more than `budget` values. The value exceeding the budget is
+/// added to its container, and the iterator is not consumed any further.
+///
+/// ```
+/// use edisp_core::prelude::*;
+///
+/// let iter = vec![Ok(1), Err("foo"), Ok(2), Err("bar"), Ok(3)].into_iter();
+/// let dispatched: Result<(Vec<_>, Vec<_>), _> = Result::dispatch_with_budget(iter, 1, 1);
+///
+/// let (some_oks, some_errs) = dispatched.unwrap_err().partial;
+/// assert_eq!(some_oks, [1, 2]);
+/// assert_eq!(some_errs, ["foo", "bar"]);
+/// ```
+pub trait DispatchWithBudget<O>
+where
+    Self: Sized,
+{
+    /// Performs dispatching, tolerating at most `budget` values of the
+    /// `failure` variant.
+    ///
+    /// # Errors
+    ///
+    /// This function returns the partially filled containers if the budget
+    /// is exceeded.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `failure` is not lower than the number of
+    /// variants.
+    fn dispatch_with_budget<I: IntoIterator<Item = Self>>(
+        iter: I,
+        failure: usize,
+        budget: usize,
+    ) -> Result<O, BudgetExceeded<O>>;
+}
+
+/// Implements `DispatchWithBudget` for every enum with a given number of
+/// variants.
+macro_rules! implement_dispatch_with_budget_for_arity {
+    (
+        $either:ident,
+        $arity:literal,
+        $( (
+            $variant:ident,
+            $t:ident,
+            $c:ident,
+            $c_name:ident,
+            $f:ident,
+            $f_name:ident,
+            $idx:tt
+        ) ),+ $( , )?
+    ) => {
+        impl<E, $( $t, )+ $( $c, )+> DispatchWithBudget<( $( $c, )+ )> for E
+        where
+            E: Variants<Either = $either< $( $t, )+ >>,
+            $( $c: Default + Extend<$t>, )+
+        {
+            fn dispatch_with_budget<I: IntoIterator<Item = Self>>(
+                iter: I,
+                failure: usize,
+                budget: usize,
+            ) -> Result<( $( $c, )+ ), BudgetExceeded<( $( $c, )+ )>> {
+                assert!(failure < $arity, "failure variant index out of range");
+
+                $( let mut $c_name = $c::default(); )+
+                let mut failures = 0;
+
+                for element in iter {
+                    let index = match element.into_either() {
+                        $(
+                            $either::$variant(value) => {
+                                $c_name.extend(Some(value));
+                                $idx
+                            }
+                        )+
+                    };
+
+                    if index == failure {
+                        failures += 1;
+
+                        if failures > budget {
+                            return Err(BudgetExceeded { partial: ( $( $c_name, )+ ) });
+                        }
+                    }
+                }
+
+                Ok(( $( $c_name, )+ ))
+            }
+        }
+    };
+}
+
+for_each_arity!(implement_dispatch_with_budget_for_arity);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dispatch_within_budget() {
+        let i = vec![Err('a'), Ok(1), Err('b')];
+        let dispatched: Result<(Vec<_>, String), _> = Result::dispatch_with_budget(i, 1, 2);
+
+        assert_eq!(dispatched, Ok((vec![1], "ab".to_string())));
+    }
+
+    #[test]
+    fn dispatch_with_budget_on_first_variant() {
+        let mut i = vec![Ok(1), Ok(2), Err('a'), Ok(3)].into_iter();
+        let dispatched: Result<(Vec<_>, String), _> =
+            Result::dispatch_with_budget(i.by_ref(), 0, 1);
+
+        assert_eq!(dispatched.unwrap_err().partial, (vec![1, 2], String::new()));
+        assert_eq!(i.next(), Some(Err('a')));
+    }
+}
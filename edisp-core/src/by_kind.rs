@@ -0,0 +1,116 @@
+//! Dispatching into a map.
+//!
+//! When an enum has a lot of variants, or when the handling of each variant is
+//! data-driven, a tuple of containers is not convenient. This module contains
+//! the `DispatchByKind` trait, which groups values in a single map, keyed by
+//! the `VariantIndex` of their variant.
+
+use alloc::collections::BTreeMap;
+#[cfg(feature = "std")]
+use core::hash::{BuildHasher, Hash};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+use crate::kind::{VariantIndex, VariantKind};
+
+/// A map whose values are containers.
+///
+/// This trait is implemented for both `HashMap` and `BTreeMap`, whose values
+/// implement `Default` and `Extend`. Containers are created the first time
+/// their key is met.
+pub trait ContainerMap<K, V> {
+    /// Adds a value in the container associated with `key`.
+    fn extend_entry(&mut self, key: K, value: V);
+}
+
+#[cfg(feature = "std")]
+impl<K, V, C, S> ContainerMap<K, V> for HashMap<K, C, S>
+where
+    K: Eq + Hash,
+    C: Default + Extend<V>,
+    S: BuildHasher,
+{
+    fn extend_entry(&mut self, key: K, value: V) {
+        self.entry(key).or_default().extend(Some(value));
+    }
+}
+
+impl<K, V, C> ContainerMap<K, V> for BTreeMap<K, C>
+where
+    K: Ord,
+    C: Default + Extend<V>,
+{
+    fn extend_entry(&mut self, key: K, value: V) {
+        self.entry(key).or_default().extend(Some(value));
+    }
+}
+
+/// A dispatcher trait grouping values by variant in a map.
+///
+/// The generic type `M` should be a map implementing `ContainerMap`. Each
+/// value is added, untouched, in the container whose key is the
+/// `VariantIndex` of its variant. Variants which are never met have no entry.
+///
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// use edisp_core::prelude::*;
+///
+/// let iter = vec![Ok(1), Err("foo"), Ok(2)].into_iter();
+/// let groups: BTreeMap<VariantIndex, Vec<_>> = Result::dispatch_by_kind(iter);
+///
+/// assert_eq!(groups[&VariantIndex::new(0)], [Ok(1), Ok(2)]);
+/// assert_eq!(groups[&VariantIndex::new(1)], [Err("foo")]);
+/// ```
+pub trait DispatchByKind<M>
+where
+    Self: Sized,
+{
+    /// Groups values by variant.
+    fn dispatch_by_kind<I: IntoIterator<Item = Self>>(iter: I) -> M;
+}
+
+impl<E, M> DispatchByKind<M> for E
+where
+    E: VariantKind,
+    M: Default + ContainerMap<VariantIndex, E>,
+{
+    fn dispatch_by_kind<I: IntoIterator<Item = Self>>(iter: I) -> M {
+        let mut map = M::default();
+
+        for element in iter {
+            map.extend_entry(element.kind(), element);
+        }
+
+        map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dispatch_by_kind_btree_map() {
+        let i = vec![Err("foo"), Ok(42), Err("bar")].into_iter();
+        let groups: BTreeMap<_, Vec<_>> = Result::dispatch_by_kind(i);
+
+        assert_eq!(
+            groups.into_iter().collect::<Vec<_>>(),
+            [
+                (VariantIndex::new(0), vec![Ok(42)]),
+                (VariantIndex::new(1), vec![Err("foo"), Err("bar")]),
+            ],
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn dispatch_by_kind_skips_missing_variants() {
+        let i = vec![Ok::<_, ()>(1), Ok(2)].into_iter();
+        let groups: HashMap<_, Vec<_>> = Result::dispatch_by_kind(i);
+
+        assert_eq!(groups.len(), 1);
+        assert!(!groups.contains_key(&VariantIndex::new(1)));
+    }
+}
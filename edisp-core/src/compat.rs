@@ -0,0 +1,21 @@
+//! Support for containers defined in other crates.
+//!
+//! Each supported crate is behind a cargo feature of the same name. This
+//! module only contains trait implementations, which allow the containers of
+//! these crates to be used with the dispatchers requiring more than `Default`
+//! and `Extend`.
+
+#[cfg(feature = "arrayvec")]
+mod arrayvec;
+#[cfg(feature = "bytes")]
+mod bytes;
+#[cfg(feature = "heapless")]
+mod heapless;
+#[cfg(feature = "indexmap")]
+mod indexmap;
+#[cfg(feature = "smallvec")]
+mod smallvec;
+#[cfg(feature = "tinyvec")]
+mod tinyvec;
+#[cfg(feature = "tokio")]
+mod tokio;
@@ -0,0 +1,54 @@
+//! Support for `ArrayVec`.
+//!
+//! `ArrayVec` has a fixed capacity. Its `Extend` implementation panics when
+//! it is full, so it should rather be used with the fallible dispatchers,
+//! `DispatchBounded` and `TryDispatch`, which handle overflows.
+
+use arrayvec::{ArrayVec, CapacityError};
+
+use crate::{bounded::IntoRefused, try_dispatch::TryExtend};
+
+impl<T, const CAP: usize> TryExtend<T> for ArrayVec<T, CAP> {
+    type Error = CapacityError<T>;
+
+    fn try_extend<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<(), CapacityError<T>> {
+        for value in iter {
+            ArrayVec::try_push(self, value)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> IntoRefused<T> for CapacityError<T> {
+    fn into_refused(self) -> T {
+        self.element()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn arrayvec_try_dispatch_overflow() {
+        let i = vec![Ok(1), Err('a'), Ok(2), Ok(3)].into_iter();
+        let dispatched: Result<(ArrayVec<_, 2>, ArrayVec<_, 2>), _> = Result::try_dispatch(i);
+
+        match dispatched {
+            Err(Either2::V1(error)) => assert_eq!(error.element(), 3),
+            _ => panic!("the first container should overflow"),
+        }
+    }
+
+    #[test]
+    fn arrayvec_dispatch_bounded_drop() {
+        let i = vec![Ok(1), Err('a'), Ok(2), Ok(3)].into_iter();
+        let (some_oks, some_errs): (ArrayVec<_, 2>, ArrayVec<_, 2>) =
+            Result::dispatch_bounded(i, OverflowPolicy::Drop).unwrap();
+
+        assert_eq!(*some_oks, [1, 2]);
+        assert_eq!(*some_errs, ['a']);
+    }
+}
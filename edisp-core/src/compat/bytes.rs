@@ -0,0 +1,48 @@
+//! Support for `BytesMut`.
+//!
+//! `BytesMut` can be extended with bytes directly. The `ByteSink` container
+//! should be used to append byte slices instead.
+
+use core::convert::Infallible;
+
+use bytes::BytesMut;
+
+use crate::{merge::Merge, reserve::ReserveHint, try_dispatch::TryExtend};
+
+impl Merge for BytesMut {
+    fn merge(&mut self, other: BytesMut) {
+        self.unsplit(other);
+    }
+}
+
+impl ReserveHint for BytesMut {
+    fn reserve(&mut self, additional: usize) {
+        BytesMut::reserve(self, additional);
+    }
+}
+
+impl TryExtend<u8> for BytesMut {
+    type Error = Infallible;
+
+    fn try_extend<I: IntoIterator<Item = u8>>(&mut self, iter: I) -> Result<(), Infallible> {
+        self.extend(iter);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn bytes_mut_collects_bytes() {
+        let i = b"a1b2"
+            .iter()
+            .map(|&b| if b.is_ascii_digit() { Ok(b) } else { Err(b) });
+        let (digits, letters): (BytesMut, BytesMut) = Result::dispatch_chunked(i, 3);
+
+        assert_eq!(&digits[..], b"12");
+        assert_eq!(&letters[..], b"ab");
+    }
+}
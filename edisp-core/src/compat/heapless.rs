@@ -0,0 +1,76 @@
+//! Support for `heapless` containers.
+//!
+//! `heapless::Vec` and `heapless::IndexMap`, and therefore `FnvIndexMap`, have
+//! a fixed capacity. Their `Extend` implementations panic when they are full,
+//! so they should rather be used with the fallible dispatchers,
+//! `DispatchBounded` and `TryDispatch`, which handle overflows.
+
+use core::hash::{BuildHasher, Hash};
+
+use heapless::{IndexMap, LenType, Vec};
+
+use crate::{bounded::Overflow, try_dispatch::TryExtend};
+
+impl<T, LenT: LenType, const N: usize> TryExtend<T> for Vec<T, N, LenT> {
+    type Error = Overflow<T>;
+
+    fn try_extend<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<(), Overflow<T>> {
+        for value in iter {
+            self.push(value).map_err(|item| Overflow { item })?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<K, V, S, const N: usize> TryExtend<(K, V)> for IndexMap<K, V, S, N>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    type Error = Overflow<(K, V)>;
+
+    fn try_extend<I: IntoIterator<Item = (K, V)>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), Overflow<(K, V)>> {
+        for (key, value) in iter {
+            self.insert(key, value).map_err(|item| Overflow { item })?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use heapless::index_map::FnvIndexMap;
+
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn heapless_vec_try_dispatch_overflow() {
+        let i = vec![Err('a'), Ok(1), Err('b'), Err('c')].into_iter();
+        let dispatched: Result<(Vec<_, 2>, Vec<_, 2>), _> = Result::try_dispatch(i);
+
+        assert_eq!(dispatched.unwrap_err(), Either2::V2(Overflow { item: 'c' }));
+    }
+
+    #[test]
+    fn fnv_index_map_dispatch_bounded_drop() {
+        let i = vec![
+            Ok(("a", 1)),
+            Ok(("b", 2)),
+            Ok(("a", 3)),
+            Ok(("c", 4)),
+            Err(()),
+        ];
+        let (map, _): (FnvIndexMap<_, _, 2>, Vec<_, 1>) =
+            Result::dispatch_bounded(i, OverflowPolicy::Drop).unwrap();
+
+        assert_eq!(map.get("a"), Some(&3));
+        assert_eq!(map.get("b"), Some(&2));
+        assert_eq!(map.get("c"), None);
+    }
+}
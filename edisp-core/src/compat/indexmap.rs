@@ -0,0 +1,90 @@
+//! Support for `IndexMap` and `IndexSet`.
+//!
+//! Both containers keep the insertion order of their entries, which makes
+//! the output of map-shaped dispatches deterministic. `IndexMap` also
+//! implements `ContainerMap`, so that it can be used with `DispatchByKind`
+//! and `GroupBy`.
+
+use core::convert::Infallible;
+use core::hash::{BuildHasher, Hash};
+
+use indexmap::{IndexMap, IndexSet};
+
+use crate::{by_kind::ContainerMap, merge::Merge, reserve::ReserveHint, try_dispatch::TryExtend};
+
+impl<K, V, C, S> ContainerMap<K, V> for IndexMap<K, C, S>
+where
+    K: Eq + Hash,
+    C: Default + Extend<V>,
+    S: BuildHasher,
+{
+    fn extend_entry(&mut self, key: K, value: V) {
+        self.entry(key).or_default().extend(Some(value));
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher> Merge for IndexMap<K, V, S> {
+    fn merge(&mut self, other: IndexMap<K, V, S>) {
+        self.extend(other);
+    }
+}
+
+impl<T: Eq + Hash, S: BuildHasher> Merge for IndexSet<T, S> {
+    fn merge(&mut self, other: IndexSet<T, S>) {
+        self.extend(other);
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher> ReserveHint for IndexMap<K, V, S> {
+    fn reserve(&mut self, additional: usize) {
+        IndexMap::reserve(self, additional);
+    }
+}
+
+impl<T: Eq + Hash, S: BuildHasher> ReserveHint for IndexSet<T, S> {
+    fn reserve(&mut self, additional: usize) {
+        IndexSet::reserve(self, additional);
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher> TryExtend<(K, V)> for IndexMap<K, V, S> {
+    type Error = Infallible;
+
+    fn try_extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) -> Result<(), Infallible> {
+        self.extend(iter);
+        Ok(())
+    }
+}
+
+impl<T: Eq + Hash, S: BuildHasher> TryExtend<T> for IndexSet<T, S> {
+    type Error = Infallible;
+
+    fn try_extend<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<(), Infallible> {
+        self.extend(iter);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{containers::GroupBy, prelude::*};
+
+    #[test]
+    fn index_set_keeps_insertion_order() {
+        let i = vec![Ok('c'), Err(()), Ok('a'), Ok('c'), Ok('b')].into_iter();
+        let (letters, _): (IndexSet<_>, Vec<_>) = Result::dispatch_chunked(i, 2);
+
+        assert_eq!(letters.into_iter().collect::<Vec<_>>(), ['c', 'a', 'b']);
+    }
+
+    #[test]
+    fn index_map_group_by() {
+        let i = vec![Ok(("b", 1)), Ok(("a", 2)), Ok(("b", 3)), Err(())].into_iter();
+        let (groups, _): (GroupBy<IndexMap<_, Vec<_>>>, Vec<_>) = Result::dispatch(i);
+
+        let groups = groups.into_inner();
+        assert_eq!(groups.keys().collect::<Vec<_>>(), [&"b", &"a"]);
+        assert_eq!(groups["b"], [1, 3]);
+    }
+}
@@ -0,0 +1,56 @@
+//! Support for `SmallVec`.
+//!
+//! `SmallVec` stores its first values inline and spills to the heap when
+//! needed, so it is never full.
+
+use core::convert::Infallible;
+
+use smallvec::{Array, SmallVec};
+
+use crate::{merge::Merge, reserve::ReserveHint, try_dispatch::TryExtend};
+
+impl<A: Array> ReserveHint for SmallVec<A> {
+    fn reserve(&mut self, additional: usize) {
+        SmallVec::reserve(self, additional);
+    }
+}
+
+impl<A: Array> Merge for SmallVec<A> {
+    fn merge(&mut self, mut other: SmallVec<A>) {
+        self.append(&mut other);
+    }
+}
+
+impl<A: Array> TryExtend<A::Item> for SmallVec<A> {
+    type Error = Infallible;
+
+    fn try_extend<I: IntoIterator<Item = A::Item>>(&mut self, iter: I) -> Result<(), Infallible> {
+        self.extend(iter);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn smallvec_stays_inline() {
+        let i = vec![Ok(1), Err('a'), Ok(2)].into_iter();
+        let (some_oks, some_errs): (SmallVec<[_; 4]>, SmallVec<[_; 4]>) =
+            Result::dispatch_reserved(i);
+
+        assert_eq!(*some_oks, [1, 2]);
+        assert!(!some_oks.spilled());
+        assert_eq!(*some_errs, ['a']);
+    }
+
+    #[test]
+    fn smallvec_merge() {
+        let i = (0..6).map(Either2::<_, ()>::V1);
+        let (values, _): (SmallVec<[_; 2]>, Vec<_>) = Either2::dispatch_chunked(i, 4);
+
+        assert_eq!(*values, [0, 1, 2, 3, 4, 5]);
+    }
+}
@@ -0,0 +1,74 @@
+//! Support for `tinyvec` containers.
+//!
+//! `TinyVec` stores its first values inline and spills to the heap when
+//! needed, so it is never full. `tinyvec::ArrayVec` has a fixed capacity: its
+//! `Extend` implementation panics when it is full, so it should rather be
+//! used with the fallible dispatchers, `DispatchBounded` and `TryDispatch`,
+//! which handle overflows.
+
+use core::convert::Infallible;
+
+use tinyvec::{Array, ArrayVec, TinyVec};
+
+use crate::{bounded::Overflow, merge::Merge, reserve::ReserveHint, try_dispatch::TryExtend};
+
+impl<A: Array> ReserveHint for TinyVec<A> {
+    fn reserve(&mut self, additional: usize) {
+        TinyVec::reserve(self, additional);
+    }
+}
+
+impl<A: Array> Merge for TinyVec<A> {
+    fn merge(&mut self, mut other: TinyVec<A>) {
+        self.append(&mut other);
+    }
+}
+
+impl<A: Array> TryExtend<A::Item> for TinyVec<A> {
+    type Error = Infallible;
+
+    fn try_extend<I: IntoIterator<Item = A::Item>>(&mut self, iter: I) -> Result<(), Infallible> {
+        self.extend(iter);
+        Ok(())
+    }
+}
+
+impl<A: Array> TryExtend<A::Item> for ArrayVec<A> {
+    type Error = Overflow<A::Item>;
+
+    fn try_extend<I: IntoIterator<Item = A::Item>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), Overflow<A::Item>> {
+        for value in iter {
+            if let Some(item) = ArrayVec::try_push(self, value) {
+                return Err(Overflow { item });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn tinyvec_spills() {
+        let i = (0..5).map(Ok::<_, ()>);
+        let (values, _): (TinyVec<[_; 2]>, Vec<_>) = Result::dispatch_chunked(i, 2);
+
+        assert_eq!(*values, [0, 1, 2, 3, 4]);
+        assert!(values.is_heap());
+    }
+
+    #[test]
+    fn tinyvec_array_vec_try_dispatch_overflow() {
+        let i = vec![Ok(1), Ok(2), Ok(3)].into_iter();
+        let dispatched: Result<(ArrayVec<[_; 2]>, Vec<()>), _> = Result::try_dispatch(i);
+
+        assert_eq!(dispatched.unwrap_err(), Either2::V1(Overflow { item: 3 }));
+    }
+}
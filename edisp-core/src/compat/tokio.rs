@@ -0,0 +1,73 @@
+//! Support for the channels of `tokio`.
+//!
+//! Senders are not `Default`, so they should be used with `TryDispatchInto`,
+//! which dispatches in existing containers. This allows to route each variant
+//! to the channel of its own task, from synchronous code.
+//!
+//! Values are sent with `try_send`, which does not wait: a bounded `Sender`
+//! refuses values when its channel is full, as well as when it is closed.
+//! Waiting for capacity requires an asynchronous sink, such as the
+//! `PollSender` of `tokio-util`, which can be used with `dispatch_to_sinks`.
+
+use tokio::sync::mpsc::error::{SendError, TrySendError};
+use tokio::sync::mpsc::{Sender, UnboundedSender};
+
+use crate::try_dispatch::TryExtend;
+
+impl<T> TryExtend<T> for Sender<T> {
+    type Error = TrySendError<T>;
+
+    fn try_extend<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<(), TrySendError<T>> {
+        for value in iter {
+            self.try_send(value)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> TryExtend<T> for UnboundedSender<T> {
+    type Error = SendError<T>;
+
+    fn try_extend<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<(), SendError<T>> {
+        for value in iter {
+            self.send(value)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::sync::mpsc;
+
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn tokio_senders_fan_out() {
+        let (ok_tx, mut ok_rx) = mpsc::channel(2);
+        let (err_tx, mut err_rx) = mpsc::unbounded_channel();
+        let mut senders = (ok_tx, err_tx);
+
+        for value in [Ok(1), Err('a'), Ok(2)] {
+            value.try_dispatch_into(&mut senders).unwrap();
+        }
+
+        let error = Ok::<_, char>(3)
+            .try_dispatch_into(&mut senders)
+            .unwrap_err();
+        assert!(matches!(error, Either2::V1(TrySendError::Full(3))));
+
+        assert_eq!(ok_rx.try_recv(), Ok(1));
+        assert_eq!(ok_rx.try_recv(), Ok(2));
+        assert_eq!(err_rx.try_recv(), Ok('a'));
+
+        drop(err_rx);
+        let error = Err::<i32, _>('b')
+            .try_dispatch_into(&mut senders)
+            .unwrap_err();
+        assert!(matches!(error, Either2::V2(SendError('b'))));
+    }
+}
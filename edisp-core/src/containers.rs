@@ -0,0 +1,206 @@
+//! Ready-to-use containers.
+//!
+//! Dispatching works with any container implementing `Default` and `Extend`.
+//! There is no dedicated container trait: the empty containers are created
+//! with `Default::default`, and each value is added to its container with
+//! `Extend::extend`. Implementing these two traits is therefore enough for a
+//! type to be used as a container.
+//!
+//! Some dispatchers need more from their containers, and express it with an
+//! additional trait:
+//!   - `ReserveHint`, for containers which can allocate memory ahead of time,
+//!   - `Merge`, for containers which can be filled separately then combined,
+//!   - `TryExtend`, for containers which may refuse a value. It provides
+//!     `FallibleExtend` when the refused value can be given back.
+//!
+//! # Standard collections
+//!
+//! Every owned collection of `std` implements `Default` and `Extend`, and can
+//! therefore be used as a container directly. `Vec`, `VecDeque`, `LinkedList`,
+//! `BinaryHeap`, `HashSet`, `BTreeSet`, `HashMap` and `BTreeMap` additionally
+//! implement `ReserveHint` and `Merge`.
+//!
+//! Maps, such as `HashMap` and `BTreeMap`, are filled with `(key, value)`
+//! pairs. When two values are dispatched with the same key, the last one
+//! replaces the first one, as with `insert`:
+//!
+//! ```
+//! use std::collections::BTreeMap;
+//!
+//! use edisp_core::prelude::*;
+//!
+//! let iter = vec![Ok(("a", 1)), Err("foo"), Ok(("b", 2)), Ok(("a", 3))].into_iter();
+//! let (map, errors): (BTreeMap<_, _>, Vec<_>) = Result::dispatch(iter);
+//!
+//! assert_eq!(map.into_iter().collect::<Vec<_>>(), [("a", 3), ("b", 2)]);
+//! assert_eq!(errors, ["foo"]);
+//! ```
+//!
+//! Sets, such as `HashSet` (which requires `Eq + Hash` values) and `BTreeSet`
+//! (which requires `Ord` values), deduplicate values while collecting them:
+//!
+//! ```
+//! use std::collections::BTreeSet;
+//!
+//! use edisp_core::prelude::*;
+//!
+//! let iter = vec![Err(404), Ok("index"), Err(500), Err(404)].into_iter();
+//! let (pages, codes): (Vec<_>, BTreeSet<_>) = Result::dispatch(iter);
+//!
+//! assert_eq!(pages, ["index"]);
+//! assert_eq!(codes.into_iter().collect::<Vec<_>>(), [404, 500]);
+//! ```
+//!
+//! `VecDeque` receives values at its back, in the order they are dispatched.
+//! The `PushFront` wrapper allows to push them at its front instead.
+//!
+//! `BinaryHeap` (which requires `Ord` values) keeps its values ordered by
+//! priority, so that the greatest one can be retrieved first:
+//!
+//! ```
+//! use std::collections::BinaryHeap;
+//!
+//! use edisp_core::prelude::*;
+//!
+//! let iter = vec![Err(2), Ok("foo"), Err(5), Err(1)].into_iter();
+//! let (_, severities): (Vec<_>, BinaryHeap<_>) = Result::dispatch(iter);
+//!
+//! assert_eq!(severities.into_sorted_vec(), [1, 2, 5]);
+//! ```
+//!
+//! `String` can be extended with `char`, `&str`, `String` or `Cow<str>`
+//! values, which are concatenated:
+//!
+//! ```
+//! use edisp_core::prelude::*;
+//!
+//! enum Token<'a> {
+//!     Word(&'a str),
+//!     Punct(char),
+//! }
+//!
+//! implement_dispatch!(Token<'a>, Word(&'a str), Punct(char));
+//!
+//! let tokens = vec![Token::Word("hello"), Token::Punct(','), Token::Word("world")];
+//! let (words, puncts): (String, String) = Token::dispatch(tokens);
+//!
+//! assert_eq!(words, "helloworld");
+//! assert_eq!(puncts, ",");
+//! ```
+//!
+//! # Provided containers
+//!
+//! This module contains containers which are not provided by `std`, and which
+//! allow, for instance, to aggregate values instead of storing them.
+
+#[cfg(feature = "bytes")]
+mod byte_sink;
+mod dedup;
+mod for_each;
+mod group_by;
+mod ignore;
+mod joined;
+mod last_n;
+mod push_front;
+mod reducers;
+mod sharded;
+#[cfg(feature = "std")]
+mod shared;
+mod slice_buffer;
+mod sorted;
+mod stats;
+mod top_k;
+mod unzip;
+#[cfg(feature = "std")]
+mod write_sink;
+
+#[cfg(feature = "bytes")]
+pub use byte_sink::ByteSink;
+pub use dedup::Dedup;
+#[cfg(feature = "std")]
+pub use dedup::DedupAll;
+pub use for_each::ForEach;
+pub use group_by::GroupBy;
+pub use ignore::Ignore;
+pub use joined::Joined;
+pub use last_n::LastN;
+pub use push_front::PushFront;
+pub use reducers::{Count, First, Last, MaxOf, MinOf, SumOf};
+pub use sharded::Sharded;
+#[cfg(feature = "std")]
+pub use shared::{Shared, SharedRw};
+pub use slice_buffer::SliceBuffer;
+pub use sorted::Sorted;
+pub use stats::Stats;
+pub use top_k::TopK;
+pub use unzip::Unzip;
+#[cfg(feature = "std")]
+pub use write_sink::WriteSink;
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cmp::Reverse,
+        collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque},
+    };
+
+    use crate::prelude::*;
+
+    #[test]
+    fn maps_keep_last_value() {
+        let values = [Either2::<_, ()>::V1((1, 'a')), Either2::V1((1, 'b'))];
+
+        let (btree_map, _): (BTreeMap<_, _>, Vec<_>) = Either2::dispatch(values.iter().copied());
+        let (hash_map, _): (HashMap<_, _>, Vec<_>) = Either2::dispatch(values.iter().copied());
+
+        assert_eq!(btree_map[&1], 'b');
+        assert_eq!(hash_map[&1], 'b');
+    }
+
+    #[test]
+    fn sets_deduplicate_values() {
+        let values = [Ok::<_, ()>('a'), Ok('b'), Ok('a')];
+
+        let (btree_set, _): (BTreeSet<_>, Vec<_>) = Result::dispatch(values.iter().copied());
+        let (hash_set, _): (HashSet<_>, Vec<_>) = Result::dispatch(values.iter().copied());
+
+        assert_eq!(btree_set.len(), 2);
+        assert_eq!(hash_set.len(), 2);
+        assert!(hash_set.contains(&'a') && hash_set.contains(&'b'));
+    }
+
+    #[test]
+    fn vec_deque_pushes_at_back() {
+        let i = vec![Either2::<_, ()>::V1(1), Either2::V1(2)].into_iter();
+        let (queue, _): (VecDeque<_>, Vec<_>) = Either2::dispatch(i);
+
+        assert_eq!(queue, [1, 2]);
+    }
+
+    #[test]
+    fn binary_heap_orders_values() {
+        let i = vec![Ok::<_, ()>(Reverse(3)), Ok(Reverse(1)), Ok(Reverse(2))].into_iter();
+        let (mut heap, _): (BinaryHeap<_>, Vec<_>) = Result::dispatch(i);
+
+        assert_eq!(heap.pop(), Some(Reverse(1)));
+        assert_eq!(heap.pop(), Some(Reverse(2)));
+    }
+
+    #[test]
+    fn linked_list_keeps_order() {
+        let i = (0..6).map(|n| if n % 2 == 0 { Ok(n) } else { Err(n) });
+        let (evens, odds): (LinkedList<_>, LinkedList<_>) = Result::dispatch_chunked(i, 4);
+
+        assert_eq!(evens.into_iter().collect::<Vec<_>>(), [0, 2, 4]);
+        assert_eq!(odds.into_iter().collect::<Vec<_>>(), [1, 3, 5]);
+    }
+
+    #[test]
+    fn string_concatenates_values() {
+        let i = vec![Ok("foo".to_string()), Err('!'), Ok("bar".to_string())];
+        let (words, puncts): (String, String) = Result::dispatch(i);
+
+        assert_eq!(words, "foobar");
+        assert_eq!(puncts, "!");
+    }
+}
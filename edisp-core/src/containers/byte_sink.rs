@@ -0,0 +1,115 @@
+//! A container concatenating bytes.
+
+use alloc::vec::Vec;
+
+use bytes::{Bytes, BytesMut};
+
+use crate::{merge::Merge, reserve::ReserveHint};
+
+/// Appends the bytes and byte slices it receives to a `BytesMut`.
+///
+/// This container is available with the `bytes` feature. It accepts `u8`,
+/// `&[u8]`, `Vec<u8>` and `Bytes` values, which makes it a natural sink for
+/// codecs classifying frames.
+///
+/// ```
+/// use edisp_core::{containers::ByteSink, prelude::*};
+///
+/// let frames = vec![Ok(&b"GET "[..]), Err("bad frame"), Ok(&b"/index"[..])];
+/// let (request, errors): (ByteSink, Vec<_>) = Result::dispatch(frames);
+///
+/// assert_eq!(&request.freeze()[..], b"GET /index");
+/// assert_eq!(errors, ["bad frame"]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ByteSink(pub BytesMut);
+
+impl ByteSink {
+    /// Returns the inner buffer.
+    pub fn into_inner(self) -> BytesMut {
+        self.0
+    }
+
+    /// Freezes the inner buffer.
+    pub fn freeze(self) -> Bytes {
+        self.0.freeze()
+    }
+}
+
+impl Extend<u8> for ByteSink {
+    fn extend<I: IntoIterator<Item = u8>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl<'a> Extend<&'a [u8]> for ByteSink {
+    fn extend<I: IntoIterator<Item = &'a [u8]>>(&mut self, iter: I) {
+        for bytes in iter {
+            self.0.extend_from_slice(bytes);
+        }
+    }
+}
+
+impl Extend<Vec<u8>> for ByteSink {
+    fn extend<I: IntoIterator<Item = Vec<u8>>>(&mut self, iter: I) {
+        for bytes in iter {
+            self.0.extend_from_slice(&bytes);
+        }
+    }
+}
+
+impl Extend<Bytes> for ByteSink {
+    fn extend<I: IntoIterator<Item = Bytes>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl Merge for ByteSink {
+    fn merge(&mut self, other: ByteSink) {
+        self.0.merge(other.0);
+    }
+}
+
+impl ReserveHint for ByteSink {
+    fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{either::Either3, prelude::*};
+
+    #[test]
+    fn byte_sink_chunked_mixed_payloads() {
+        let i = vec![
+            Either3::V1(b"GET ".to_vec()),
+            Either3::V3(0),
+            Either3::V2(Bytes::from_static(b"/index")),
+            Either3::V1(b".html".to_vec()),
+            Either3::V3(1),
+            Either3::V2(Bytes::from_static(b"\r\n")),
+        ];
+        let (vecs, bytes, single_bytes): (ByteSink, ByteSink, ByteSink) =
+            Either3::dispatch_chunked(i, 2);
+
+        assert_eq!(&vecs.freeze()[..], b"GET .html");
+        assert_eq!(&bytes.freeze()[..], b"/index\r\n");
+        assert_eq!(&single_bytes.freeze()[..], [0, 1]);
+    }
+
+    #[test]
+    fn byte_sink_merge_appends() {
+        let mut sink = ByteSink::default();
+        sink.extend(vec![b'a', b'b']);
+
+        let mut other = ByteSink::default();
+        other.extend(vec![Bytes::from_static(b"cd")]);
+        other.extend(vec![b"ef".to_vec()]);
+
+        sink.merge(other);
+
+        assert_eq!(&sink.freeze()[..], b"abcdef");
+    }
+}
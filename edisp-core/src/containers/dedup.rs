@@ -0,0 +1,140 @@
+//! Containers skipping duplicate values.
+
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::hash::Hash;
+#[cfg(feature = "std")]
+use std::collections::HashSet;
+
+/// Skips the values equal to the previous one, and adds the other ones to an
+/// inner container.
+///
+/// This allows repeated values, such as the same error reported several times
+/// in a row, to be collapsed during dispatching. Only consecutive duplicates
+/// are skipped: `DedupAll` skips every value which has already been met.
+///
+/// ```
+/// use edisp_core::{containers::Dedup, prelude::*};
+///
+/// let iter = vec![Err("timeout"), Err("timeout"), Ok(1), Err("refused"), Err("timeout")];
+/// let (some_oks, errors): (Vec<_>, Dedup<_>) = Result::dispatch(iter);
+///
+/// assert_eq!(some_oks, [1]);
+/// assert_eq!(errors.into_inner(), ["timeout", "refused", "timeout"]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Dedup<T, C = Vec<T>> {
+    inner: C,
+    last: Option<T>,
+}
+
+impl<T, C> Dedup<T, C> {
+    /// Returns the inner container.
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+}
+
+impl<T, C: Default> Default for Dedup<T, C> {
+    fn default() -> Dedup<T, C> {
+        Dedup {
+            inner: C::default(),
+            last: None,
+        }
+    }
+}
+
+impl<T, C> Extend<T> for Dedup<T, C>
+where
+    T: PartialEq + Clone,
+    C: Extend<T>,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            if self.last.as_ref() != Some(&value) {
+                self.last = Some(value.clone());
+                self.inner.extend(Some(value));
+            }
+        }
+    }
+}
+
+/// Skips the values which have already been met, and adds the other ones to
+/// an inner container.
+///
+/// Every distinct value is kept in a `HashSet`, so that it is skipped when it
+/// is met again. Unlike a `HashSet` used as a container, the inner container
+/// keeps the order in which values were first met.
+///
+/// ```
+/// use edisp_core::{containers::DedupAll, prelude::*};
+///
+/// let iter = vec![Err("timeout"), Ok(1), Err("refused"), Err("timeout")];
+/// let (some_oks, errors): (Vec<_>, DedupAll<_>) = Result::dispatch(iter);
+///
+/// assert_eq!(some_oks, [1]);
+/// assert_eq!(errors.into_inner(), ["timeout", "refused"]);
+/// ```
+#[cfg(feature = "std")]
+#[derive(Clone, Debug)]
+pub struct DedupAll<T, C = Vec<T>> {
+    inner: C,
+    seen: HashSet<T>,
+}
+
+#[cfg(feature = "std")]
+impl<T, C> DedupAll<T, C> {
+    /// Returns the inner container.
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, C: Default> Default for DedupAll<T, C> {
+    fn default() -> DedupAll<T, C> {
+        DedupAll {
+            inner: C::default(),
+            seen: HashSet::new(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, C> Extend<T> for DedupAll<T, C>
+where
+    T: Eq + Hash + Clone,
+    C: Extend<T>,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            if !self.seen.contains(&value) {
+                self.seen.insert(value.clone());
+                self.inner.extend(Some(value));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{containers::Count, prelude::*};
+
+    #[test]
+    fn dedup_skips_consecutive_values() {
+        let i = vec![Ok(1), Ok(1), Err(()), Ok(1), Ok(2), Ok(1)].into_iter();
+        let (some_oks, _): (Dedup<_, Count>, Vec<_>) = Result::dispatch(i);
+
+        assert_eq!(some_oks.into_inner().into_inner(), 3);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn dedup_all_skips_every_duplicate() {
+        let i = vec![Ok(1), Ok(2), Err(()), Ok(1), Ok(3), Ok(2)].into_iter();
+        let (some_oks, _): (DedupAll<_, Count>, Vec<_>) = Result::dispatch(i);
+
+        assert_eq!(some_oks.into_inner().into_inner(), 3);
+    }
+}
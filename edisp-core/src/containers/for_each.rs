@@ -0,0 +1,56 @@
+//! A container calling a closure.
+
+/// Calls a closure on each value it receives.
+///
+/// This allows to redirect a single variant to a side effect, such as logging,
+/// while the other ones are collected. Closures do not implement `Default`,
+/// so `Dispatcher::from_containers` can be used to dispatch in a `ForEach`.
+/// `DispatchForEach` can be used instead when every variant should be handled
+/// by a closure.
+///
+/// ```
+/// use edisp_core::{containers::ForEach, prelude::*};
+///
+/// let mut log = Vec::new();
+///
+/// let mut dispatcher =
+///     Dispatcher::from_containers((Vec::new(), ForEach(|err| log.push(format!("error: {}", err)))));
+/// dispatcher.push_iter(vec![Ok(1), Err("foo"), Ok(2)]);
+///
+/// let (some_oks, _) = dispatcher.finish();
+/// assert_eq!(some_oks, [1, 2]);
+/// assert_eq!(log, ["error: foo"]);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct ForEach<F>(pub F);
+
+impl<F> ForEach<F> {
+    /// Returns the inner closure.
+    pub fn into_inner(self) -> F {
+        self.0
+    }
+}
+
+impl<T, F: FnMut(T)> Extend<T> for ForEach<F> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(&mut self.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn for_each_in_parts() {
+        let mut sum = 0;
+
+        let mut parts = Parts((ForEach(|n| sum += n), Vec::new()));
+        parts.extend(vec![Ok(1), Err('a'), Ok(2)]);
+
+        let (_, some_errs) = parts.into_inner();
+        assert_eq!(some_errs, ['a']);
+        assert_eq!(sum, 3);
+    }
+}
@@ -0,0 +1,103 @@
+//! A container grouping pairs by key.
+
+use alloc::collections::{btree_map, BTreeMap};
+#[cfg(feature = "std")]
+use core::hash::{BuildHasher, Hash};
+#[cfg(feature = "std")]
+use std::collections::{hash_map, HashMap};
+
+use crate::{by_kind::ContainerMap, merge::Merge};
+
+/// Groups the `(key, value)` pairs it receives by key.
+///
+/// The generic type `M` should be a map implementing `ContainerMap`, such as
+/// `HashMap<K, C>` or `BTreeMap<K, C>`, `C` being the container in which the
+/// values associated with a given key are added. Containers are created the
+/// first time their key is met.
+///
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// use edisp_core::{containers::GroupBy, prelude::*};
+///
+/// let iter = vec![Ok(("a", 1)), Err("foo"), Ok(("b", 2)), Ok(("a", 3))].into_iter();
+/// let (groups, some_errs): (GroupBy<BTreeMap<_, Vec<_>>>, Vec<_>) = Result::dispatch(iter);
+///
+/// let groups = groups.into_inner();
+/// assert_eq!(groups["a"], [1, 3]);
+/// assert_eq!(groups["b"], [2]);
+/// assert_eq!(some_errs, ["foo"]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GroupBy<M>(pub M);
+
+impl<M> GroupBy<M> {
+    /// Returns the inner map.
+    pub fn into_inner(self) -> M {
+        self.0
+    }
+}
+
+impl<K, V, M> Extend<(K, V)> for GroupBy<M>
+where
+    M: ContainerMap<K, V>,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.0.extend_entry(key, value);
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, C, S> Merge for GroupBy<HashMap<K, C, S>>
+where
+    K: Eq + Hash,
+    C: Merge,
+    S: BuildHasher,
+{
+    fn merge(&mut self, other: GroupBy<HashMap<K, C, S>>) {
+        for (key, container) in other.0 {
+            match self.0.entry(key) {
+                hash_map::Entry::Occupied(mut entry) => entry.get_mut().merge(container),
+                hash_map::Entry::Vacant(entry) => {
+                    entry.insert(container);
+                }
+            }
+        }
+    }
+}
+
+impl<K, C> Merge for GroupBy<BTreeMap<K, C>>
+where
+    K: Ord,
+    C: Merge,
+{
+    fn merge(&mut self, other: GroupBy<BTreeMap<K, C>>) {
+        for (key, container) in other.0 {
+            match self.0.entry(key) {
+                btree_map::Entry::Occupied(mut entry) => entry.get_mut().merge(container),
+                btree_map::Entry::Vacant(entry) => {
+                    entry.insert(container);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::{containers::Count, prelude::*};
+
+    #[test]
+    fn group_by_merges_groups() {
+        let i = (0..10).map(|n| Ok::<_, ()>((n % 3, ())));
+        let (groups, _): (GroupBy<HashMap<_, Count>>, Vec<_>) = Result::dispatch_chunked(i, 4);
+
+        let groups = groups.into_inner();
+        assert_eq!(groups[&0].into_inner(), 4);
+        assert_eq!(groups[&1].into_inner(), 3);
+        assert_eq!(groups[&2].into_inner(), 3);
+    }
+}
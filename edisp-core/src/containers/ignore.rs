@@ -0,0 +1,43 @@
+//! A container discarding values.
+
+use crate::merge::Merge;
+
+/// Discards the values it receives.
+///
+/// This allows to drop the variants which are not needed, without allocating
+/// memory for them.
+///
+/// ```
+/// use edisp_core::{containers::Ignore, prelude::*};
+///
+/// let iter = vec![Ok(1), Err("foo"), Ok(2)].into_iter();
+/// let (some_oks, Ignore): (Vec<_>, Ignore) = Result::dispatch(iter);
+///
+/// assert_eq!(some_oks, [1, 2]);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ignore;
+
+impl<T> Extend<T> for Ignore {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(drop);
+    }
+}
+
+impl Merge for Ignore {
+    fn merge(&mut self, _other: Ignore) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn ignore_drops_values() {
+        let i = vec![Ok(1), Err(String::from("foo")), Ok(2)];
+        let (Ignore, some_errs): (_, Vec<_>) = Result::dispatch(i);
+
+        assert_eq!(some_errs, ["foo"]);
+    }
+}
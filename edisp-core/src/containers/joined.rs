@@ -0,0 +1,124 @@
+//! A container joining values in a string.
+
+use alloc::string::String;
+use core::fmt::{Display, Write};
+
+use crate::merge::Merge;
+
+/// Formats the values it receives, and joins them with a separator.
+///
+/// The default separator is `", "`. Another one can be chosen with
+/// `with_separator`, and the container used with
+/// `Dispatcher::from_containers`.
+///
+/// ```
+/// use edisp_core::{containers::Joined, prelude::*};
+///
+/// let iter = vec![Ok(1), Err("timeout"), Ok(2), Err("refused")].into_iter();
+/// let (some_oks, errors): (Vec<_>, Joined) = Result::dispatch(iter);
+///
+/// assert_eq!(some_oks, [1, 2]);
+/// assert_eq!(errors.into_inner(), "timeout, refused");
+///
+/// let mut dispatcher = Dispatcher::from_containers((Joined::with_separator(" | "), Vec::new()));
+/// dispatcher.push_iter(vec![Ok(1), Err("timeout"), Ok(2)]);
+///
+/// let (some_oks, _) = dispatcher.finish();
+/// assert_eq!(some_oks.into_inner(), "1 | 2");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Joined {
+    buffer: String,
+    separator: String,
+    has_values: bool,
+}
+
+impl Joined {
+    /// Creates a container joining values with `separator`.
+    ///
+    /// The separator is not kept by `DispatchChunked`, which creates the
+    /// container of each chunk with `Default`: values are then joined with
+    /// `", "`.
+    pub fn with_separator<S: Into<String>>(separator: S) -> Joined {
+        Joined {
+            buffer: String::new(),
+            separator: separator.into(),
+            has_values: false,
+        }
+    }
+
+    /// Returns the joined values.
+    pub fn as_str(&self) -> &str {
+        &self.buffer
+    }
+
+    /// Returns the joined values.
+    pub fn into_inner(self) -> String {
+        self.buffer
+    }
+
+    /// Adds the separator, unless no value has been added yet.
+    fn push_separator(&mut self) {
+        if self.has_values {
+            self.buffer.push_str(&self.separator);
+        }
+        self.has_values = true;
+    }
+}
+
+impl Default for Joined {
+    fn default() -> Joined {
+        Joined::with_separator(", ")
+    }
+}
+
+impl<T: Display> Extend<T> for Joined {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_separator();
+            // Writing to a `String` can not fail.
+            let _ = write!(self.buffer, "{}", value);
+        }
+    }
+}
+
+/// Joins the values of `other` after the values of `self`.
+///
+/// The separator of `self` is used, both between the two containers and in
+/// the result. The separator of `other` is not checked, so the values of
+/// `other` are kept as they were joined.
+impl Merge for Joined {
+    fn merge(&mut self, other: Joined) {
+        if other.has_values {
+            self.push_separator();
+            self.buffer.push_str(&other.buffer);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn joined_merge_separates_chunks() {
+        let i = vec![Ok(""), Err(1), Ok("a"), Ok("b"), Err(2)].into_iter();
+        let (some_oks, some_errs): (Joined, Joined) = Result::dispatch_chunked(i, 2);
+
+        assert_eq!(some_oks.as_str(), ", a, b");
+        assert_eq!(some_errs.as_str(), "1, 2");
+    }
+
+    #[test]
+    fn joined_merge_keeps_own_separator() {
+        let mut joined = Joined::with_separator(" | ");
+        joined.extend(vec![1, 2]);
+
+        let mut other = Joined::default();
+        other.extend(vec![3, 4]);
+
+        joined.merge(other);
+        assert_eq!(joined.as_str(), "1 | 2 | 3, 4");
+    }
+}
@@ -0,0 +1,84 @@
+//! A container keeping the most recent values it receives.
+
+use alloc::collections::VecDeque;
+
+use crate::merge::Merge;
+
+/// Keeps the `N` last values it receives.
+///
+/// Values are stored in a ring buffer of `N` values. When it is full, the
+/// oldest value is dropped to make room for the new one. This allows to keep
+/// a rolling window of the most recent occurrences of a variant.
+///
+/// ```
+/// use edisp_core::{containers::LastN, prelude::*};
+///
+/// let iter = vec![Err("a"), Ok(1), Err("b"), Err("c"), Ok(2), Err("d")].into_iter();
+/// let (some_oks, recent_errs): (Vec<_>, LastN<_, 2>) = Result::dispatch(iter);
+///
+/// assert_eq!(some_oks, [1, 2]);
+/// assert_eq!(recent_errs.into_inner(), ["c", "d"]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LastN<T, const N: usize>(VecDeque<T>);
+
+impl<T, const N: usize> LastN<T, N> {
+    /// Returns an iterator over the values kept, the oldest one first.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.0.iter()
+    }
+
+    /// Returns the values kept, the oldest one first.
+    pub fn into_inner(self) -> VecDeque<T> {
+        self.0
+    }
+}
+
+impl<T, const N: usize> Default for LastN<T, N> {
+    fn default() -> LastN<T, N> {
+        LastN(VecDeque::with_capacity(N))
+    }
+}
+
+impl<T, const N: usize> Extend<T> for LastN<T, N> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        if N == 0 {
+            return;
+        }
+
+        for value in iter {
+            if self.0.len() == N {
+                self.0.pop_front();
+            }
+            self.0.push_back(value);
+        }
+    }
+}
+
+impl<T, const N: usize> Merge for LastN<T, N> {
+    fn merge(&mut self, other: LastN<T, N>) {
+        self.extend(other.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn last_n_merge_keeps_most_recent() {
+        let i = (0..10).map(Ok::<_, ()>);
+        let (recent, _): (LastN<_, 3>, Vec<_>) = Result::dispatch_chunked(i, 4);
+
+        assert_eq!(recent.iter().copied().collect::<Vec<_>>(), [7, 8, 9]);
+    }
+
+    #[test]
+    fn last_n_with_zero_capacity() {
+        let i = vec![Ok::<_, ()>(1), Ok(2)].into_iter();
+        let (recent, _): (LastN<_, 0>, Vec<_>) = Result::dispatch(i);
+
+        assert!(recent.into_inner().is_empty());
+    }
+}
@@ -0,0 +1,67 @@
+//! A queue receiving values at its front.
+
+use alloc::collections::VecDeque;
+
+use crate::{merge::Merge, reserve::ReserveHint};
+
+/// Adds the values it receives at the front of a `VecDeque`.
+///
+/// A `VecDeque` used as a container receives its values at the back, in the
+/// order they are dispatched. This wrapper pushes them at the front instead,
+/// so that the most recent value comes first.
+///
+/// ```
+/// use edisp_core::{containers::PushFront, prelude::*};
+///
+/// let iter = vec![Ok(1), Err("foo"), Ok(2), Ok(3)].into_iter();
+/// let (some_oks, some_errs): (PushFront<_>, Vec<_>) = Result::dispatch(iter);
+///
+/// assert_eq!(some_oks.into_inner(), [3, 2, 1]);
+/// assert_eq!(some_errs, ["foo"]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PushFront<T>(pub VecDeque<T>);
+
+impl<T> PushFront<T> {
+    /// Returns the inner queue.
+    pub fn into_inner(self) -> VecDeque<T> {
+        self.0
+    }
+}
+
+impl<T> Extend<T> for PushFront<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.0.push_front(value);
+        }
+    }
+}
+
+impl<T> Merge for PushFront<T> {
+    fn merge(&mut self, mut other: PushFront<T>) {
+        // Values of `other` were dispatched after the values of `self`, so
+        // they come first.
+        other.0.append(&mut self.0);
+        self.0 = other.0;
+    }
+}
+
+impl<T> ReserveHint for PushFront<T> {
+    fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn push_front_merge_keeps_reverse_order() {
+        let i = (0..10).map(Ok::<_, ()>);
+        let (some_oks, _): (PushFront<_>, Vec<_>) = Result::dispatch_chunked(i, 3);
+
+        assert_eq!(some_oks.into_inner(), [9, 8, 7, 6, 5, 4, 3, 2, 1, 0]);
+    }
+}
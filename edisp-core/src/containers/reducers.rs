@@ -0,0 +1,257 @@
+//! Containers aggregating values instead of storing them.
+
+use core::ops::AddAssign;
+
+use crate::merge::Merge;
+
+/// Counts the values it receives.
+///
+/// ```
+/// use edisp_core::{containers::Count, prelude::*};
+///
+/// let iter = vec![Ok(1), Err("foo"), Ok(2)].into_iter();
+/// let (some_oks, errors): (Vec<_>, Count) = Result::dispatch(iter);
+///
+/// assert_eq!(some_oks, [1, 2]);
+/// assert_eq!(errors.into_inner(), 1);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Count(pub usize);
+
+impl Count {
+    /// Returns the number of values received.
+    pub fn into_inner(self) -> usize {
+        self.0
+    }
+}
+
+impl<T> Extend<T> for Count {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0 += iter.into_iter().count();
+    }
+}
+
+impl Merge for Count {
+    fn merge(&mut self, other: Count) {
+        self.0 += other.0;
+    }
+}
+
+/// Sums the values it receives.
+///
+/// The sum starts at the default value of `T`, which is zero for numbers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SumOf<T>(pub T);
+
+impl<T> SumOf<T> {
+    /// Returns the sum of the values received.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: AddAssign> Extend<T> for SumOf<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.0 += value;
+        }
+    }
+}
+
+impl<T: AddAssign> Merge for SumOf<T> {
+    fn merge(&mut self, other: SumOf<T>) {
+        self.0 += other.0;
+    }
+}
+
+/// Keeps the smallest value it receives.
+///
+/// If several values are equally small, the first one is kept.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MinOf<T>(pub Option<T>);
+
+impl<T> MinOf<T> {
+    /// Returns the smallest value received, if any.
+    pub fn into_inner(self) -> Option<T> {
+        self.0
+    }
+}
+
+impl<T> Default for MinOf<T> {
+    fn default() -> MinOf<T> {
+        MinOf(None)
+    }
+}
+
+impl<T: Ord> Extend<T> for MinOf<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            match self.0 {
+                Some(ref min) if *min <= value => {}
+                _ => self.0 = Some(value),
+            }
+        }
+    }
+}
+
+impl<T: Ord> Merge for MinOf<T> {
+    fn merge(&mut self, other: MinOf<T>) {
+        self.extend(other.0);
+    }
+}
+
+/// Keeps the greatest value it receives.
+///
+/// If several values are equally great, the last one is kept.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MaxOf<T>(pub Option<T>);
+
+impl<T> MaxOf<T> {
+    /// Returns the greatest value received, if any.
+    pub fn into_inner(self) -> Option<T> {
+        self.0
+    }
+}
+
+impl<T> Default for MaxOf<T> {
+    fn default() -> MaxOf<T> {
+        MaxOf(None)
+    }
+}
+
+impl<T: Ord> Extend<T> for MaxOf<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            match self.0 {
+                Some(ref max) if *max > value => {}
+                _ => self.0 = Some(value),
+            }
+        }
+    }
+}
+
+impl<T: Ord> Merge for MaxOf<T> {
+    fn merge(&mut self, other: MaxOf<T>) {
+        self.extend(other.0);
+    }
+}
+
+/// Keeps the first value it receives.
+///
+/// Only one slot is needed, so the values which come after the first one are
+/// dropped without being stored.
+///
+/// ```
+/// use edisp_core::{containers::First, prelude::*};
+///
+/// let iter = vec![Ok(1), Err("foo"), Ok(2), Err("bar")].into_iter();
+/// let (some_oks, first_err): (Vec<_>, First<_>) = Result::dispatch(iter);
+///
+/// assert_eq!(some_oks, [1, 2]);
+/// assert_eq!(first_err.into_inner(), Some("foo"));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct First<T>(pub Option<T>);
+
+impl<T> First<T> {
+    /// Returns the first value received, if any.
+    pub fn into_inner(self) -> Option<T> {
+        self.0
+    }
+}
+
+impl<T> Default for First<T> {
+    fn default() -> First<T> {
+        First(None)
+    }
+}
+
+impl<T> Extend<T> for First<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        if self.0.is_none() {
+            self.0 = iter.into_iter().next();
+        }
+    }
+}
+
+impl<T> Merge for First<T> {
+    fn merge(&mut self, other: First<T>) {
+        self.extend(other.0);
+    }
+}
+
+/// Keeps the last value it receives.
+///
+/// Only one slot is needed, so the values which come before the last one are
+/// dropped as soon as a newer value is received.
+///
+/// ```
+/// use edisp_core::{containers::Last, prelude::*};
+///
+/// let iter = vec![Ok(1), Err("foo"), Ok(2), Err("bar")].into_iter();
+/// let (some_oks, last_err): (Vec<_>, Last<_>) = Result::dispatch(iter);
+///
+/// assert_eq!(some_oks, [1, 2]);
+/// assert_eq!(last_err.into_inner(), Some("bar"));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Last<T>(pub Option<T>);
+
+impl<T> Last<T> {
+    /// Returns the last value received, if any.
+    pub fn into_inner(self) -> Option<T> {
+        self.0
+    }
+}
+
+impl<T> Default for Last<T> {
+    fn default() -> Last<T> {
+        Last(None)
+    }
+}
+
+impl<T> Extend<T> for Last<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        if let Some(value) = iter.into_iter().last() {
+            self.0 = Some(value);
+        }
+    }
+}
+
+impl<T> Merge for Last<T> {
+    fn merge(&mut self, other: Last<T>) {
+        self.extend(other.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn reducers_aggregate() {
+        let i = vec![Ok(3), Err("foo"), Ok(1), Err("bar"), Ok(2)];
+
+        let (sum, errors): (SumOf<_>, Count) = Result::dispatch(i.clone());
+        assert_eq!(sum.into_inner(), 6);
+        assert_eq!(errors.into_inner(), 2);
+
+        let (min, max): (MinOf<_>, MaxOf<_>) = Result::dispatch(i.clone());
+        assert_eq!(min.into_inner(), Some(1));
+        assert_eq!(max.into_inner(), Some("foo"));
+
+        let (first, last): (First<_>, Last<_>) = Result::dispatch(i);
+        assert_eq!(first.into_inner(), Some(3));
+        assert_eq!(last.into_inner(), Some("bar"));
+    }
+
+    #[test]
+    fn reducers_without_values() {
+        let i: Vec<Result<u8, u8>> = vec![];
+        let (min, last): (MinOf<_>, Last<_>) = Result::dispatch(i);
+
+        assert_eq!(min.into_inner(), None);
+        assert_eq!(last.into_inner(), None);
+    }
+}
@@ -0,0 +1,96 @@
+//! A container made of several shards.
+
+use alloc::vec::Vec;
+
+use crate::merge::Merge;
+
+/// Keeps the values dispatched by each chunk in a separate container.
+///
+/// Merging two `Sharded` containers does not merge their inner containers:
+/// the shards of `other` are moved after the ones of `self`, which does not
+/// depend on the number of values they hold. This makes `Sharded` a cheap
+/// output for `DispatchChunked`, when each chunk can be processed on its own
+/// afterwards. The shards can still be merged together with `into_merged`.
+///
+/// ```
+/// use edisp_core::{containers::Sharded, prelude::*};
+///
+/// let iter = (1..=6).map(|n| if n % 3 == 0 { Err(n) } else { Ok(n) });
+/// let (some_oks, some_errs): (Sharded<Vec<_>>, Vec<_>) = Result::dispatch_chunked(iter, 3);
+///
+/// assert_eq!(some_oks.shards(), [vec![1, 2], vec![4, 5]]);
+/// assert_eq!(some_oks.into_merged(), [1, 2, 4, 5]);
+/// assert_eq!(some_errs, [3, 6]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Sharded<C>(Vec<C>);
+
+impl<C> Sharded<C> {
+    /// Returns the shards, in the order their values were dispatched.
+    pub fn shards(&self) -> &[C] {
+        &self.0
+    }
+
+    /// Returns the shards, in the order their values were dispatched.
+    pub fn into_shards(self) -> Vec<C> {
+        self.0
+    }
+
+    /// Merges every shard in a single container.
+    pub fn into_merged(self) -> C
+    where
+        C: Default + Merge,
+    {
+        let mut shards = self.0.into_iter();
+        let mut merged = shards.next().unwrap_or_default();
+
+        for shard in shards {
+            merged.merge(shard);
+        }
+
+        merged
+    }
+}
+
+impl<T, C> Extend<T> for Sharded<C>
+where
+    C: Default + Extend<T>,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut iter = iter.into_iter().peekable();
+        if iter.peek().is_none() {
+            return;
+        }
+
+        match self.0.last_mut() {
+            Some(shard) => shard.extend(iter),
+            None => {
+                let mut shard = C::default();
+                shard.extend(iter);
+                self.0.push(shard);
+            }
+        }
+    }
+}
+
+impl<C> Merge for Sharded<C> {
+    fn merge(&mut self, mut other: Sharded<C>) {
+        self.0.append(&mut other.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn sharded_skips_empty_chunks() {
+        let i = (0..6).map(|n| if n < 4 { Ok(n) } else { Err(n) });
+        let (some_oks, some_errs): (Sharded<Vec<_>>, Sharded<Vec<_>>) =
+            Result::dispatch_chunked(i, 2);
+
+        assert_eq!(some_oks.into_shards(), [vec![0, 1], vec![2, 3]]);
+        assert_eq!(some_errs.into_shards(), [vec![4, 5]]);
+    }
+}
@@ -0,0 +1,178 @@
+//! Containers shared between threads.
+
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError, RwLock, RwLockReadGuard};
+
+/// A container shared between threads, behind a `Mutex`.
+///
+/// Cloning a `Shared` container gives another handle to the same inner
+/// container. Each handle can be used as a container, for instance by a
+/// `Dispatcher` running on its own thread, so that values dispatched by
+/// several threads are aggregated in the same output.
+///
+/// The lock is taken once per call to `extend`. If a thread panicked while
+/// holding it, the values it added are kept, and the lock is used anyway.
+///
+/// ```
+/// use std::thread;
+///
+/// use edisp_core::{containers::Shared, prelude::*};
+///
+/// let errors = Shared::<Vec<_>>::default();
+///
+/// let handles = (0..4)
+///     .map(|n| {
+///         let mut dispatcher = Dispatcher::from_containers((Vec::new(), errors.clone()));
+///         thread::spawn(move || {
+///             dispatcher.push_iter(vec![Ok(n), Err(n * 10)]);
+///             dispatcher.finish().0
+///         })
+///     })
+///     .collect::<Vec<_>>();
+///
+/// for handle in handles {
+///     assert_eq!(handle.join().unwrap().len(), 1);
+/// }
+///
+/// let mut errors = errors.try_into_inner().unwrap();
+/// errors.sort();
+/// assert_eq!(errors, [0, 10, 20, 30]);
+/// ```
+#[derive(Debug, Default)]
+pub struct Shared<C>(Arc<Mutex<C>>);
+
+impl<C> Shared<C> {
+    /// Creates a shared container.
+    pub fn new(container: C) -> Shared<C> {
+        Shared(Arc::new(Mutex::new(container)))
+    }
+
+    /// Locks the inner container.
+    pub fn lock(&self) -> MutexGuard<'_, C> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Returns the inner container, if this is its last handle.
+    ///
+    /// # Errors
+    ///
+    /// This function gives the handle back if other handles exist.
+    pub fn try_into_inner(self) -> Result<C, Shared<C>> {
+        Arc::try_unwrap(self.0)
+            .map(|mutex| mutex.into_inner().unwrap_or_else(PoisonError::into_inner))
+            .map_err(Shared)
+    }
+}
+
+impl<C> Clone for Shared<C> {
+    fn clone(&self) -> Shared<C> {
+        Shared(Arc::clone(&self.0))
+    }
+}
+
+impl<C> From<Arc<Mutex<C>>> for Shared<C> {
+    fn from(container: Arc<Mutex<C>>) -> Shared<C> {
+        Shared(container)
+    }
+}
+
+impl<T, C: Extend<T>> Extend<T> for Shared<C> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.lock().extend(iter);
+    }
+}
+
+/// A container shared between threads, behind a `RwLock`.
+///
+/// This is similar to `Shared`, except that several threads can read the
+/// inner container at the same time, while no value is being added.
+///
+/// ```
+/// use edisp_core::{containers::SharedRw, prelude::*};
+///
+/// let some_oks = SharedRw::<Vec<_>>::default();
+///
+/// let mut dispatcher = Dispatcher::from_containers((some_oks.clone(), Vec::new()));
+/// dispatcher.push_iter(vec![Ok(1), Err("foo"), Ok(2)]);
+///
+/// assert_eq!(*some_oks.read(), [1, 2]);
+/// ```
+#[derive(Debug, Default)]
+pub struct SharedRw<C>(Arc<RwLock<C>>);
+
+impl<C> SharedRw<C> {
+    /// Creates a shared container.
+    pub fn new(container: C) -> SharedRw<C> {
+        SharedRw(Arc::new(RwLock::new(container)))
+    }
+
+    /// Locks the inner container for reading.
+    pub fn read(&self) -> RwLockReadGuard<'_, C> {
+        self.0.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Returns the inner container, if this is its last handle.
+    ///
+    /// # Errors
+    ///
+    /// This function gives the handle back if other handles exist.
+    pub fn try_into_inner(self) -> Result<C, SharedRw<C>> {
+        Arc::try_unwrap(self.0)
+            .map(|lock| lock.into_inner().unwrap_or_else(PoisonError::into_inner))
+            .map_err(SharedRw)
+    }
+}
+
+impl<C> Clone for SharedRw<C> {
+    fn clone(&self) -> SharedRw<C> {
+        SharedRw(Arc::clone(&self.0))
+    }
+}
+
+impl<C> From<Arc<RwLock<C>>> for SharedRw<C> {
+    fn from(container: Arc<RwLock<C>>) -> SharedRw<C> {
+        SharedRw(container)
+    }
+}
+
+impl<T, C: Extend<T>> Extend<T> for SharedRw<C> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .extend(iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+    use crate::{containers::Count, prelude::*};
+
+    #[test]
+    fn shared_rw_across_threads() {
+        let counts = SharedRw::<Count>::default();
+
+        thread::scope(|scope| {
+            for _ in 0..4 {
+                let mut counts = counts.clone();
+                scope.spawn(move || counts.extend(0..10));
+            }
+        });
+
+        assert_eq!(counts.try_into_inner().unwrap().into_inner(), 40);
+    }
+
+    #[test]
+    fn shared_try_into_inner_with_other_handles() {
+        let some_oks = Shared::new(vec![0]);
+        let handle = some_oks.clone();
+
+        Ok::<_, ()>(1).dispatch_into(&mut (handle.clone(), Vec::new()));
+
+        let some_oks = some_oks.try_into_inner().unwrap_err();
+        drop(handle);
+        assert_eq!(some_oks.try_into_inner().unwrap(), [0, 1]);
+    }
+}
@@ -0,0 +1,82 @@
+//! A container producing frozen slices.
+
+use alloc::{boxed::Box, rc::Rc, sync::Arc, vec::Vec};
+
+use crate::{merge::Merge, reserve::ReserveHint};
+
+/// Buffers the values it receives, so that they can be turned into a boxed or
+/// shared slice once dispatching is done.
+///
+/// Slices such as `Box<[T]>`, `Rc<[T]>` or `Arc<[T]>` can not be extended, as
+/// their length is fixed. This container stores values in a `Vec`, which is
+/// converted at the end.
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use edisp_core::{containers::SliceBuffer, prelude::*};
+///
+/// let iter = vec![Ok(1), Err("foo"), Ok(2)].into_iter();
+/// let (some_oks, some_errs): (SliceBuffer<_>, SliceBuffer<_>) = Result::dispatch(iter);
+///
+/// let some_oks: Arc<[_]> = some_oks.into_arc();
+/// assert_eq!(*some_oks, [1, 2]);
+/// assert_eq!(*some_errs.into_boxed_slice(), ["foo"]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SliceBuffer<T>(pub Vec<T>);
+
+impl<T> SliceBuffer<T> {
+    /// Returns the inner buffer.
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+
+    /// Converts the buffer into a boxed slice.
+    pub fn into_boxed_slice(self) -> Box<[T]> {
+        self.0.into_boxed_slice()
+    }
+
+    /// Converts the buffer into a reference-counted slice.
+    pub fn into_rc(self) -> Rc<[T]> {
+        Rc::from(self.0)
+    }
+
+    /// Converts the buffer into an atomically reference-counted slice.
+    pub fn into_arc(self) -> Arc<[T]> {
+        Arc::from(self.0)
+    }
+}
+
+impl<T> Extend<T> for SliceBuffer<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl<T> Merge for SliceBuffer<T> {
+    fn merge(&mut self, other: SliceBuffer<T>) {
+        self.0.merge(other.0);
+    }
+}
+
+impl<T> ReserveHint for SliceBuffer<T> {
+    fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn slice_buffer_into_rc() {
+        let i = vec![Ok('a'), Err(()), Ok('b')].into_iter();
+        let (some_oks, some_errs): (SliceBuffer<_>, SliceBuffer<_>) = Result::dispatch_reserved(i);
+
+        assert_eq!(*some_oks.into_rc(), ['a', 'b']);
+        assert_eq!(some_errs.into_inner(), [()]);
+    }
+}
@@ -0,0 +1,89 @@
+//! A container keeping its values sorted.
+
+use alloc::vec::Vec;
+use core::mem;
+
+use crate::{merge::Merge, reserve::ReserveHint};
+
+/// Keeps the values it receives sorted in a `Vec`.
+///
+/// Each value is inserted at its position, found by binary search, so that
+/// the values are ready to be searched or merged once dispatching is done.
+/// Equal values are kept in the order they were dispatched.
+///
+/// ```
+/// use edisp_core::{containers::Sorted, prelude::*};
+///
+/// let iter = vec![Ok(3), Err("foo"), Ok(1), Ok(2)].into_iter();
+/// let (some_oks, some_errs): (Sorted<_>, Vec<_>) = Result::dispatch(iter);
+///
+/// assert_eq!(some_oks.as_slice(), [1, 2, 3]);
+/// assert!(some_oks.as_slice().binary_search(&2).is_ok());
+/// assert_eq!(some_errs, ["foo"]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Sorted<T>(Vec<T>);
+
+impl<T> Sorted<T> {
+    /// Returns the sorted values.
+    pub fn as_slice(&self) -> &[T] {
+        &self.0
+    }
+
+    /// Returns the inner buffer, whose values are sorted.
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T: Ord> Extend<T> for Sorted<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            let idx = self.0.partition_point(|other| *other <= value);
+            self.0.insert(idx, value);
+        }
+    }
+}
+
+impl<T: Ord> Merge for Sorted<T> {
+    fn merge(&mut self, other: Sorted<T>) {
+        let mut left = mem::take(&mut self.0).into_iter().peekable();
+        let mut right = other.0.into_iter().peekable();
+        self.0.reserve(left.len() + right.len());
+
+        // Values of `self` come first when values are equal, as they were
+        // dispatched before the values of `other`.
+        while let (Some(l), Some(r)) = (left.peek(), right.peek()) {
+            let next = if l <= r { left.next() } else { right.next() };
+            self.0.extend(next);
+        }
+
+        self.0.extend(left);
+        self.0.extend(right);
+    }
+}
+
+impl<T> ReserveHint for Sorted<T> {
+    fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn sorted_merge_keeps_order() {
+        let i = [5, 2, 8, 1, 9, 3, 7]
+            .iter()
+            .map(|n| Ok::<_, ()>((n % 4, *n)));
+        let (some_oks, _): (Sorted<_>, Vec<_>) = Result::dispatch_chunked(i, 3);
+
+        assert_eq!(
+            some_oks.into_inner(),
+            [(0, 8), (1, 1), (1, 5), (1, 9), (2, 2), (3, 3), (3, 7)],
+        );
+    }
+}
@@ -0,0 +1,190 @@
+//! A container summarizing numbers.
+
+use core::time::Duration;
+
+use crate::merge::Merge;
+
+/// Summarizes the numbers it receives, without storing them.
+///
+/// The count, mean, minimum, maximum and variance of the values are updated
+/// as they are received, using Welford's algorithm. Values are converted to
+/// `f64`. `Duration`s are converted to seconds.
+///
+/// ```
+/// use edisp_core::{containers::Stats, prelude::*};
+///
+/// let iter = vec![Ok(2), Err("timeout"), Ok(4), Ok(9)].into_iter();
+/// let (latencies, errors): (Stats, Vec<_>) = Result::dispatch(iter);
+///
+/// assert_eq!(latencies.count(), 3);
+/// assert_eq!(latencies.mean(), Some(5.0));
+/// assert_eq!(latencies.min(), Some(2.0));
+/// assert_eq!(latencies.max(), Some(9.0));
+/// assert_eq!(latencies.variance(), Some(26.0 / 3.0));
+/// assert_eq!(errors, ["timeout"]);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Stats {
+    count: usize,
+    mean: f64,
+    m2: f64,
+    min: f64,
+    max: f64,
+}
+
+impl Stats {
+    /// Returns the number of values received.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the mean of the values received, if any.
+    pub fn mean(&self) -> Option<f64> {
+        self.if_any(self.mean)
+    }
+
+    /// Returns the smallest value received, if any.
+    pub fn min(&self) -> Option<f64> {
+        self.if_any(self.min)
+    }
+
+    /// Returns the greatest value received, if any.
+    pub fn max(&self) -> Option<f64> {
+        self.if_any(self.max)
+    }
+
+    /// Returns the population variance of the values received, if any.
+    pub fn variance(&self) -> Option<f64> {
+        self.if_any(self.m2 / self.count as f64)
+    }
+
+    /// Returns the sample variance of the values received, if at least two
+    /// values were received.
+    pub fn sample_variance(&self) -> Option<f64> {
+        if self.count < 2 {
+            None
+        } else {
+            Some(self.m2 / (self.count - 1) as f64)
+        }
+    }
+
+    /// Returns the population standard deviation of the values received, if
+    /// any.
+    ///
+    /// This method is only available with the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn std_dev(&self) -> Option<f64> {
+        self.variance().map(f64::sqrt)
+    }
+
+    /// Returns `value` if at least one value was received.
+    fn if_any(&self, value: f64) -> Option<f64> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(value)
+        }
+    }
+
+    /// Adds a value to the summary.
+    fn push(&mut self, value: f64) {
+        if self.count == 0 {
+            self.min = value;
+            self.max = value;
+        } else {
+            self.min = self.min.min(value);
+            self.max = self.max.max(value);
+        }
+
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+}
+
+/// Implements `Extend` for `Stats`, for numeric types which can be converted
+/// to `f64` with `as`.
+macro_rules! implement_stats_extend {
+    ( $( $ty:ty ),+ $( , )? ) => {
+        $(
+            impl Extend<$ty> for Stats {
+                fn extend<I: IntoIterator<Item = $ty>>(&mut self, iter: I) {
+                    for value in iter {
+                        self.push(value as f64);
+                    }
+                }
+            }
+
+            impl<'a> Extend<&'a $ty> for Stats {
+                fn extend<I: IntoIterator<Item = &'a $ty>>(&mut self, iter: I) {
+                    self.extend(iter.into_iter().copied());
+                }
+            }
+        )+
+    };
+}
+
+implement_stats_extend!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+impl Extend<Duration> for Stats {
+    fn extend<I: IntoIterator<Item = Duration>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value.as_secs_f64());
+        }
+    }
+}
+
+impl Merge for Stats {
+    fn merge(&mut self, other: Stats) {
+        if other.count == 0 {
+            return;
+        }
+
+        if self.count == 0 {
+            *self = other;
+            return;
+        }
+
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+
+        self.mean += delta * other.count as f64 / count as f64;
+        self.m2 += other.m2 + delta * delta * (self.count * other.count) as f64 / count as f64;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+        self.count = count;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn stats_merge_chunks() {
+        let i = (1..=10).map(|n| if n % 4 == 0 { Err(n) } else { Ok(n as f32) });
+        let (chunked, _): (Stats, Vec<_>) = Result::dispatch_chunked(i.clone(), 3);
+        let (stats, _): (Stats, Vec<_>) = Result::dispatch(i);
+
+        assert_eq!(chunked.count(), 8);
+        assert_eq!(chunked.min(), Some(1.0));
+        assert_eq!(chunked.max(), Some(10.0));
+        assert!((chunked.mean().unwrap() - stats.mean().unwrap()).abs() < 1e-9);
+        assert!((chunked.variance().unwrap() - stats.variance().unwrap()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn stats_with_few_values() {
+        let i = vec![Ok::<_, ()>(Duration::from_secs(1))];
+        let (stats, _): (Stats, Vec<_>) = Result::dispatch(i);
+        assert_eq!(stats.sample_variance(), None);
+        #[cfg(feature = "std")]
+        assert_eq!(stats.std_dev(), Some(0.0));
+
+        let stats = Stats::default();
+        assert_eq!(stats.mean(), None);
+        assert_eq!(stats.variance(), None);
+    }
+}
@@ -0,0 +1,103 @@
+//! A container keeping the greatest values it receives.
+
+use alloc::{collections::BinaryHeap, vec::Vec};
+use core::cmp::Reverse;
+
+use crate::merge::Merge;
+
+/// Keeps the `K` greatest values it receives.
+///
+/// Values are stored in a binary heap holding at most `K` values. When it is
+/// full, each new value replaces the smallest stored value if it is greater,
+/// and is dropped otherwise. Memory use is therefore bounded, whatever the
+/// number of dispatched values.
+///
+/// The smallest values can be kept by wrapping them in `std::cmp::Reverse`.
+/// Values can be compared by key by dispatching `(key, value)` pairs.
+///
+/// ```
+/// use edisp_core::{containers::TopK, prelude::*};
+///
+/// let requests = vec![Ok(("/a", 120)), Err("/b"), Ok(("/c", 450)), Ok(("/d", 80))];
+/// let iter = requests.into_iter().map(|r| r.map(|(path, ms)| (ms, path)));
+/// let (slowest, failures): (TopK<_, 2>, Vec<_>) = Result::dispatch(iter);
+///
+/// assert_eq!(slowest.into_sorted_vec(), [(450, "/c"), (120, "/a")]);
+/// assert_eq!(failures, ["/b"]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct TopK<T, const K: usize>(BinaryHeap<Reverse<T>>);
+
+impl<T, const K: usize> TopK<T, K> {
+    /// Returns the number of values kept.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if no value was received.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<T: Ord, const K: usize> TopK<T, K> {
+    /// Returns the values kept, the greatest one first.
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        self.0
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(value)| value)
+            .collect()
+    }
+}
+
+impl<T: Ord, const K: usize> Default for TopK<T, K> {
+    fn default() -> TopK<T, K> {
+        TopK(BinaryHeap::with_capacity(K))
+    }
+}
+
+impl<T: Ord, const K: usize> Extend<T> for TopK<T, K> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            if self.0.len() < K {
+                self.0.push(Reverse(value));
+            } else if let Some(mut smallest) = self.0.peek_mut() {
+                if smallest.0 < value {
+                    *smallest = Reverse(value);
+                }
+            }
+        }
+    }
+}
+
+impl<T: Ord, const K: usize> Merge for TopK<T, K> {
+    fn merge(&mut self, other: TopK<T, K>) {
+        self.extend(other.0.into_iter().map(|Reverse(value)| value));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn top_k_keeps_smallest_values() {
+        let i = [5, 2, 8, 1, 9, 3].iter().map(|n| Ok::<_, ()>(Reverse(*n)));
+        let (smallest, _): (TopK<_, 3>, Vec<_>) = Result::dispatch_chunked(i, 2);
+
+        assert_eq!(
+            smallest.into_sorted_vec(),
+            [Reverse(1), Reverse(2), Reverse(3)]
+        );
+    }
+
+    #[test]
+    fn top_k_with_zero_capacity() {
+        let i = vec![Ok::<_, ()>(1), Ok(2)].into_iter();
+        let (top, _): (TopK<_, 0>, Vec<_>) = Result::dispatch(i);
+
+        assert!(top.is_empty());
+    }
+}
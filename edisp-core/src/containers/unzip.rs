@@ -0,0 +1,67 @@
+//! A container splitting pairs.
+
+use crate::merge::Merge;
+
+/// Splits the pairs it receives between two containers.
+///
+/// The first element of each pair is added to the first container, and the
+/// second element to the second one. This allows, for instance, to split
+/// `(key, value)` pairs during a dispatch.
+///
+/// ```
+/// use edisp_core::{containers::Unzip, prelude::*};
+///
+/// let iter = vec![Ok(("foo", 1)), Err("bar"), Ok(("baz", 2))].into_iter();
+/// let (pairs, some_errs): (Unzip<Vec<_>, Vec<_>>, Vec<_>) = Result::dispatch(iter);
+///
+/// let (keys, values) = pairs.into_inner();
+/// assert_eq!(keys, ["foo", "baz"]);
+/// assert_eq!(values, [1, 2]);
+/// assert_eq!(some_errs, ["bar"]);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Unzip<C1, C2>(pub C1, pub C2);
+
+impl<C1, C2> Unzip<C1, C2> {
+    /// Returns both inner containers.
+    pub fn into_inner(self) -> (C1, C2) {
+        (self.0, self.1)
+    }
+}
+
+impl<A, B, C1, C2> Extend<(A, B)> for Unzip<C1, C2>
+where
+    C1: Extend<A>,
+    C2: Extend<B>,
+{
+    fn extend<I: IntoIterator<Item = (A, B)>>(&mut self, iter: I) {
+        for (a, b) in iter {
+            self.0.extend(Some(a));
+            self.1.extend(Some(b));
+        }
+    }
+}
+
+impl<C1: Merge, C2: Merge> Merge for Unzip<C1, C2> {
+    fn merge(&mut self, other: Unzip<C1, C2>) {
+        self.0.merge(other.0);
+        self.1.merge(other.1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::*;
+    use crate::{containers::SumOf, prelude::*};
+
+    #[test]
+    fn unzip_in_different_containers() {
+        let i = vec![Ok(("foo", 3)), Ok(("bar", 4)), Err(()), Ok(("foo", 5))];
+        let (Unzip(names, total), _): (Unzip<BTreeSet<_>, SumOf<_>>, Vec<_>) = Result::dispatch(i);
+
+        assert_eq!(names.into_iter().collect::<Vec<_>>(), ["bar", "foo"]);
+        assert_eq!(total.into_inner(), 12);
+    }
+}
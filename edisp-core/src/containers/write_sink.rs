@@ -0,0 +1,153 @@
+//! A container writing values through an `io::Write` implementor.
+
+use std::io::{self, Write};
+
+use crate::try_dispatch::TryExtend;
+
+/// Writes the bytes and strings it receives to a writer.
+///
+/// This allows to stream a variant straight to a file or a socket, while the
+/// other ones are collected in memory. As `Extend` can not fail, the first
+/// error met is kept, and nothing is written after it. It is returned by
+/// `finish`. `TryDispatch` can be used instead to stop dispatching as soon as
+/// an error occurs.
+///
+/// Writers usually do not implement `Default`, so `Dispatcher::from_containers`
+/// can be used to dispatch in an existing `WriteSink`.
+///
+/// ```
+/// use edisp_core::{containers::WriteSink, prelude::*};
+///
+/// let mut dispatcher = Dispatcher::from_containers((Vec::new(), WriteSink::new(Vec::new())));
+/// dispatcher.push_iter(vec![Ok(1), Err("foo\n"), Ok(2), Err("bar\n")]);
+///
+/// let (some_oks, log) = dispatcher.finish();
+/// assert_eq!(some_oks, [1, 2]);
+/// assert_eq!(log.finish().unwrap(), b"foo\nbar\n");
+/// ```
+#[derive(Debug, Default)]
+pub struct WriteSink<W> {
+    writer: W,
+    error: Option<io::Error>,
+}
+
+impl<W> WriteSink<W> {
+    /// Creates a container writing to `writer`.
+    pub fn new(writer: W) -> WriteSink<W> {
+        WriteSink {
+            writer,
+            error: None,
+        }
+    }
+
+    /// Returns a reference to the writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Returns the writer, or the first error met while writing.
+    ///
+    /// # Errors
+    ///
+    /// This function returns the first error returned by the writer.
+    pub fn finish(self) -> io::Result<W> {
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(self.writer),
+        }
+    }
+}
+
+impl<W: Write> WriteSink<W> {
+    /// Writes `bytes`, unless an error has already been met.
+    fn write(&mut self, bytes: &[u8]) {
+        if self.error.is_none() {
+            self.error = self.writer.write_all(bytes).err();
+        }
+    }
+}
+
+/// Implements `Extend` and `TryExtend` for `WriteSink`, for a given type of
+/// values which can be viewed as bytes.
+macro_rules! implement_write_sink_extend {
+    ( $( [ $( $generics:tt )* ] $item:ty ),+ $( , )? ) => {
+        $(
+            impl< $( $generics )* W: Write> Extend<$item> for WriteSink<W> {
+                fn extend<I: IntoIterator<Item = $item>>(&mut self, iter: I) {
+                    for value in iter {
+                        self.write(value.as_ref());
+                    }
+                }
+            }
+
+            impl< $( $generics )* W: Write> TryExtend<$item> for WriteSink<W> {
+                type Error = io::Error;
+
+                fn try_extend<I: IntoIterator<Item = $item>>(
+                    &mut self,
+                    iter: I,
+                ) -> io::Result<()> {
+                    for value in iter {
+                        let bytes: &[u8] = value.as_ref();
+                        self.writer.write_all(bytes)?;
+                    }
+
+                    Ok(())
+                }
+            }
+        )+
+    };
+}
+
+implement_write_sink_extend! {
+    ['a,] &'a [u8],
+    ['a,] &'a str,
+    [] Vec<u8>,
+    [] String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    /// A writer which fails once it holds four bytes.
+    #[derive(Debug, Default)]
+    struct Small(Vec<u8>);
+
+    impl Write for Small {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            let len = buf.len().min(4 - self.0.len());
+            if len == 0 {
+                return Err(io::Error::new(io::ErrorKind::WriteZero, "full"));
+            }
+
+            self.0.extend_from_slice(&buf[..len]);
+            Ok(len)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn write_sink_keeps_first_error() {
+        let i = vec![Ok("abc"), Err(()), Ok("def"), Ok("g")];
+        let (sink, _): (WriteSink<Small>, Vec<_>) = Result::dispatch(i);
+
+        assert_eq!(sink.get_ref().0, b"abcd");
+        assert_eq!(sink.finish().unwrap_err().kind(), io::ErrorKind::WriteZero);
+    }
+
+    #[test]
+    fn write_sink_try_dispatch() {
+        let i = vec![Ok(vec![1, 2, 3]), Ok(vec![4, 5]), Err(())];
+        let dispatched: Result<(WriteSink<Small>, Vec<_>), _> = Result::try_dispatch(i);
+
+        match dispatched {
+            Err(Either2::V1(error)) => assert_eq!(error.kind(), io::ErrorKind::WriteZero),
+            _ => panic!("the writer should be full"),
+        }
+    }
+}
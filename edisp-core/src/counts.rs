@@ -0,0 +1,147 @@
+//! Counting dispatched values.
+//!
+//! Sometimes, only the number of values of each variant matters. This module
+//! contains the `DispatchCounts` trait, which counts them without allocating
+//! anything, and the `VariantStats` summary, which allows to quickly check the
+//! health of a stream before dispatching it for real.
+
+use crate::{dispatch::Variants, either::EitherN};
+
+/// A dispatcher trait which only counts values.
+///
+/// The returned array contains, for each variant, the number of values the
+/// iterator yielded. It is implemented for every type implementing
+/// `Variants`.
+///
+/// ```
+/// use edisp_core::prelude::*;
+///
+/// let iter = vec![Ok(1), Err("foo"), Ok(2)].into_iter();
+/// let [oks, errs] = Result::dispatch_counts(iter);
+///
+/// assert_eq!(oks, 2);
+/// assert_eq!(errs, 1);
+/// ```
+pub trait DispatchCounts
+where
+    Self: Sized,
+{
+    /// An array of counters, one for each variant.
+    type Counts: AsRef<[usize]>;
+
+    /// Counts the values of each variant.
+    fn dispatch_counts<I: IntoIterator<Item = Self>>(iter: I) -> Self::Counts;
+
+    /// Counts the values of each variant, and summarizes them.
+    ///
+    /// ```
+    /// use edisp_core::prelude::*;
+    ///
+    /// let iter = vec![Ok(1), Err("foo"), Ok(2), Ok(3)].into_iter();
+    /// let stats = Result::dispatch_stats(iter);
+    ///
+    /// assert_eq!(stats.total(), 4);
+    /// assert_eq!(*stats.counts(), [3, 1]);
+    /// assert_eq!(stats.ratio(1), 0.25);
+    /// ```
+    fn dispatch_stats<I: IntoIterator<Item = Self>>(iter: I) -> VariantStats<Self::Counts> {
+        VariantStats::new(Self::dispatch_counts(iter))
+    }
+}
+
+/// A summary of the number of values of each variant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct VariantStats<C> {
+    counts: C,
+    total: usize,
+}
+
+impl<C: AsRef<[usize]>> VariantStats<C> {
+    /// Creates a summary from the number of values of each variant.
+    pub fn new(counts: C) -> VariantStats<C> {
+        let total = counts.as_ref().iter().sum();
+        VariantStats { counts, total }
+    }
+
+    /// Returns the total number of values.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Returns the number of values of each variant.
+    pub fn counts(&self) -> &C {
+        &self.counts
+    }
+
+    /// Returns the number of values of the variant at `index`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `index` is not lower than the number of
+    /// variants.
+    pub fn count(&self, index: usize) -> usize {
+        self.counts.as_ref()[index]
+    }
+
+    /// Returns the proportion of values of the variant at `index`, between
+    /// `0` and `1`.
+    ///
+    /// If no value was counted, the ratio is `0`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `index` is not lower than the number of
+    /// variants.
+    pub fn ratio(&self, index: usize) -> f64 {
+        if self.total == 0 {
+            return 0.0;
+        }
+
+        self.count(index) as f64 / self.total as f64
+    }
+
+    /// Returns an iterator over the proportion of values of each variant.
+    pub fn ratios(&self) -> impl Iterator<Item = f64> + '_ {
+        (0..self.counts.as_ref().len()).map(move |index| self.ratio(index))
+    }
+}
+
+impl<E> DispatchCounts for E
+where
+    E: Variants,
+    E::Either: EitherN,
+{
+    type Counts = <E::Either as EitherN>::Counts;
+
+    fn dispatch_counts<I: IntoIterator<Item = Self>>(iter: I) -> Self::Counts {
+        let mut counts = Self::Counts::default();
+
+        for element in iter {
+            counts.as_mut()[element.into_either().index()] += 1;
+        }
+
+        counts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::either::Either3;
+
+    #[test]
+    fn dispatch_counts_counts_each_variant() {
+        let i = vec![Err("foo"), Ok(42), Ok(101), Err("bar"), Ok(0)].into_iter();
+        let counts = Result::dispatch_counts(i);
+
+        assert_eq!(counts, [3, 2]);
+    }
+
+    #[test]
+    fn dispatch_stats_without_values() {
+        let stats = Either3::<u8, u8, u8>::dispatch_stats(vec![]);
+
+        assert_eq!(stats.total(), 0);
+        assert_eq!(stats.ratios().collect::<Vec<_>>(), [0.0; 3]);
+    }
+}
@@ -0,0 +1,156 @@
+//! Dispatching values while they are deserialized.
+//!
+//! Deserializing a sequence of enums, then dispatching it, requires the whole
+//! sequence to be held in memory first. This module contains the
+//! `DispatchSeed` type, which dispatches each element of a serialized
+//! sequence as soon as it is deserialized, and the `dispatch_seq` function,
+//! which can be used with `#[serde(deserialize_with = "...")]`.
+//!
+//! This module is only available with the `serde` feature.
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use serde::de::{Deserialize, DeserializeSeed, Deserializer, SeqAccess, Visitor};
+
+use crate::dispatcher::DispatchInto;
+
+/// Deserializes a sequence of `E`, and dispatches its elements in containers.
+///
+/// `DispatchSeed` implements `DeserializeSeed`. Each element is dispatched
+/// in its container right after it is deserialized, so that the sequence is
+/// never stored as a whole. Empty containers can be created with `new`, or
+/// given with `from_containers`, so that several sequences are dispatched in
+/// the same containers.
+///
+/// ```
+/// use edisp_core::prelude::*;
+/// use serde::de::DeserializeSeed;
+///
+/// let json = r#"[{ "Ok": 1 }, { "Err": "foo" }, { "Ok": 2 }]"#;
+/// let mut deserializer = serde_json::Deserializer::from_str(json);
+///
+/// let (some_oks, some_errs): (Vec<_>, Vec<_>) =
+///     DispatchSeed::<Result<i32, String>, _>::new()
+///         .deserialize(&mut deserializer)
+///         .unwrap();
+///
+/// assert_eq!(some_oks, [1, 2]);
+/// assert_eq!(some_errs, ["foo"]);
+/// ```
+pub struct DispatchSeed<E, O> {
+    containers: O,
+    marker: PhantomData<fn() -> E>,
+}
+
+impl<E, O: Default> DispatchSeed<E, O> {
+    /// Creates a seed dispatching values in empty containers.
+    pub fn new() -> DispatchSeed<E, O> {
+        DispatchSeed::from_containers(O::default())
+    }
+}
+
+impl<E, O> DispatchSeed<E, O> {
+    /// Creates a seed dispatching values in existing containers.
+    pub fn from_containers(containers: O) -> DispatchSeed<E, O> {
+        DispatchSeed {
+            containers,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<E, O: Default> Default for DispatchSeed<E, O> {
+    fn default() -> DispatchSeed<E, O> {
+        DispatchSeed::new()
+    }
+}
+
+impl<'de, E, O> DeserializeSeed<'de> for DispatchSeed<E, O>
+where
+    E: Deserialize<'de> + DispatchInto<O>,
+{
+    type Value = O;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<O, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, E, O> Visitor<'de> for DispatchSeed<E, O>
+where
+    E: Deserialize<'de> + DispatchInto<O>,
+{
+    type Value = O;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a sequence")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(mut self, mut seq: A) -> Result<O, A::Error> {
+        while let Some(value) = seq.next_element::<E>()? {
+            value.dispatch_into(&mut self.containers);
+        }
+
+        Ok(self.containers)
+    }
+}
+
+/// Deserializes a sequence of `E`, and dispatches its elements in empty
+/// containers.
+///
+/// This is a shortcut for `DispatchSeed::new().deserialize(deserializer)`,
+/// whose signature allows it to be used with `deserialize_with`.
+///
+/// ```
+/// use edisp_core::containers::Count;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Report {
+///     #[serde(deserialize_with = "edisp_core::de::dispatch_seq::<Option<u8>, _, _>")]
+///     samples: (Vec<u8>, Count),
+/// }
+///
+/// let json = r#"{ "samples": [4, null, 2, null, null] }"#;
+/// let report: Report = serde_json::from_str(json).unwrap();
+///
+/// assert_eq!(report.samples.0, [4, 2]);
+/// assert_eq!(report.samples.1.into_inner(), 3);
+/// ```
+pub fn dispatch_seq<'de, E, O, D>(deserializer: D) -> Result<O, D::Error>
+where
+    E: Deserialize<'de> + DispatchInto<O>,
+    O: Default,
+    D: Deserializer<'de>,
+{
+    DispatchSeed::<E, O>::new().deserialize(deserializer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dispatch_seed_from_containers() {
+        let mut containers = (vec![0], Vec::new());
+
+        for json in &["[1, null]", "[null, 2, 3]"] {
+            let mut deserializer = serde_json::Deserializer::from_str(json);
+            containers = DispatchSeed::<Option<i32>, _>::from_containers(containers)
+                .deserialize(&mut deserializer)
+                .unwrap();
+        }
+
+        assert_eq!(containers, (vec![0, 1, 2, 3], vec![(), ()]));
+    }
+
+    #[test]
+    fn dispatch_seed_invalid_element() {
+        let mut deserializer = serde_json::Deserializer::from_str(r#"[1, "foo"]"#);
+        let dispatched: Result<(Vec<u8>, Vec<()>), _> =
+            DispatchSeed::<Option<u8>, _>::new().deserialize(&mut deserializer);
+
+        assert!(dispatched.is_err());
+    }
+}
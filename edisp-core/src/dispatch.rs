@@ -5,6 +5,10 @@
 //! regardless the number of variants it contains. This trait should however be
 //! implemented for enums with two variants or more.
 
+use core::iter::FromIterator;
+
+use crate::{either::*, map::DispatchMap};
+
 /// A dispatcher trait.
 ///
 /// This trait is general enough to be usable on every enum, regardless of the
@@ -13,19 +17,222 @@
 /// The generic type `O` should be a tuple whose arity is equal to the number
 /// of variants of the implementor, and should contain only types which
 /// implement `Default` and `Extend`.
+///
+/// This trait is implemented for every type implementing [`Variants`].
+///
+/// [`Variants`]: trait.Variants.html
 pub trait Dispatch<O>
 where
     Self: Sized,
 {
     /// Performs dispatching.
-    fn dispatch<I: Iterator<Item = Self>>(iter: I) -> O;
+    ///
+    /// Any type implementing `IntoIterator` can be dispatched, which allows
+    /// to pass collections directly:
+    ///
+    /// ```
+    /// use edisp_core::prelude::*;
+    ///
+    /// let (some_oks, some_errs): (Vec<_>, Vec<_>) = Result::dispatch(vec![Ok(1), Err("foo")]);
+    ///
+    /// assert_eq!(some_oks, [1]);
+    /// assert_eq!(some_errs, ["foo"]);
+    /// ```
+    fn dispatch<I: IntoIterator<Item = Self>>(iter: I) -> O;
+}
+
+/// Allows to call `dispatch` directly on iterators.
+///
+/// This trait is implemented for every iterator. It does the same thing as
+/// `Dispatch::dispatch`, with method-call syntax:
+///
+/// ```
+/// use edisp_core::prelude::*;
+///
+/// let iter = vec![Ok(1), Err("foo"), Ok(2)].into_iter();
+/// let (some_oks, some_errs) = iter.dispatch::<(Vec<_>, Vec<_>)>();
+///
+/// assert_eq!(some_oks, [1, 2]);
+/// assert_eq!(some_errs, ["foo"]);
+/// ```
+pub trait DispatchIterator: Iterator
+where
+    Self: Sized,
+{
+    /// Dispatches the values yielded by the iterator.
+    fn dispatch<O>(self) -> O
+    where
+        Self::Item: Dispatch<O>,
+    {
+        Self::Item::dispatch(self)
+    }
+
+    /// Classifies the values yielded by the iterator and dispatches them.
+    ///
+    /// This does the same thing as `DispatchMap::dispatch_map`, with
+    /// method-call syntax:
+    ///
+    /// ```
+    /// use edisp_core::prelude::*;
+    ///
+    /// let (small, big) = (1..=5).dispatch_map::<_, (Vec<_>, Vec<_>), _>(|n| {
+    ///     if n < 3 {
+    ///         Ok(n)
+    ///     } else {
+    ///         Err(n)
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(small, [1, 2]);
+    /// assert_eq!(big, [3, 4, 5]);
+    /// ```
+    fn dispatch_map<E, O, F>(self, f: F) -> O
+    where
+        F: FnMut(Self::Item) -> E,
+        E: DispatchMap<O>,
+    {
+        E::dispatch_map(self, f)
+    }
+
+    /// Classifies the values yielded by the iterator and dispatches them,
+    /// discarding the ones for which `f` returns `None`.
+    ///
+    /// This does the same thing as `DispatchMap::dispatch_filter_map`, with
+    /// method-call syntax.
+    fn dispatch_filter_map<E, O, F>(self, f: F) -> O
+    where
+        F: FnMut(Self::Item) -> Option<E>,
+        E: DispatchMap<O>,
+    {
+        E::dispatch_filter_map(self, f)
+    }
 }
 
+impl<I: Iterator> DispatchIterator for I {}
+
+/// The result of a dispatch performed by `collect`.
+///
+/// `Dispatched` implements `FromIterator` for every enum which can be
+/// dispatched in its inner containers, which allows to dispatch with
+/// `Iterator::collect`:
+///
+/// ```
+/// use edisp_core::prelude::*;
+///
+/// let iter = vec![Ok(1), Err("foo"), Ok(2)].into_iter();
+/// let Dispatched((some_oks, some_errs)): Dispatched<(Vec<_>, Vec<_>)> = iter.collect();
+///
+/// assert_eq!(some_oks, [1, 2]);
+/// assert_eq!(some_errs, ["foo"]);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Dispatched<O>(pub O);
+
+impl<O> Dispatched<O> {
+    /// Returns the inner containers.
+    pub fn into_inner(self) -> O {
+        self.0
+    }
+}
+
+impl<E, O> FromIterator<E> for Dispatched<O>
+where
+    E: Dispatch<O>,
+{
+    fn from_iter<I: IntoIterator<Item = E>>(iter: I) -> Dispatched<O> {
+        Dispatched(E::dispatch(iter))
+    }
+}
+
+/// An enum whose variants can be dispatched.
+///
+/// This trait allows to convert an enum into the `EitherN` type which has the
+/// same number of variants, and back. Every dispatching flavor provided by
+/// `edisp` is built on top of these conversions, so that implementing them is
+/// enough to get all of them.
+///
+/// This trait is implemented by both the `implement_dispatch` macro and the
+/// `Dispatch` derive macro. The conversions are expected to be simple
+/// `match`es, which the compiler optimizes away.
+///
+/// As the `EitherN` type depends on the number of variants, this trait can be
+/// used to require an enum with a given number of variants:
+///
+/// ```
+/// use edisp_core::prelude::*;
+///
+/// fn count_failures<E, T, U>(values: Vec<E>) -> usize
+/// where
+///     E: Variants<Either = Either2<T, U>>,
+/// {
+///     let (_, failures): (Vec<T>, Vec<U>) = E::dispatch(values);
+///     failures.len()
+/// }
+///
+/// assert_eq!(count_failures(vec![Ok(1), Err("foo"), Err("bar")]), 2);
+/// ```
+pub trait Variants
+where
+    Self: Sized,
+{
+    /// The `EitherN` type associated with the implementor.
+    ///
+    /// The `n`-th variant of the implementor must be converted to the `n`-th
+    /// variant of this type.
+    type Either;
+
+    /// Converts a value into its `EitherN` representation.
+    fn into_either(self) -> Self::Either;
+
+    /// Converts an `EitherN` representation back into a value.
+    fn from_either(either: Self::Either) -> Self;
+}
+
+/// Implements `Dispatch` for every enum with a given number of variants.
+macro_rules! implement_dispatch_for_arity {
+    (
+        $either:ident,
+        $arity:literal,
+        $( (
+            $variant:ident,
+            $t:ident,
+            $c:ident,
+            $c_name:ident,
+            $f:ident,
+            $f_name:ident,
+            $idx:tt
+        ) ),+ $( , )?
+    ) => {
+        impl<E, $( $t, )+ $( $c, )+> Dispatch<( $( $c, )+ )> for E
+        where
+            E: Variants<Either = $either< $( $t, )+ >>,
+            $( $c: Default + Extend<$t>, )+
+        {
+            fn dispatch<I: IntoIterator<Item = Self>>(iter: I) -> ( $( $c, )+ ) {
+                $( let mut $c_name = $c::default(); )+
+
+                for element in iter {
+                    match element.into_either() {
+                        $( $either::$variant(value) => $c_name.extend(Some(value)), )+
+                    }
+                }
+
+                ( $( $c_name, )+ )
+            }
+        }
+    };
+}
+
+for_each_arity!(implement_dispatch_for_arity);
+
 /// Implements a given dispatcher trait for a given enum.
 ///
 /// This macro is meant to be used internally, and should **not** be called
 /// by the user. It does not bring any new feature, and won't be faster or
 /// whetever.
+///
+/// It only implements `Dispatch`, directly. `implement_dispatch` implements
+/// `Variants` instead, which provides every dispatching flavor.
 #[macro_export]
 macro_rules! implement_dispatcher_trait {
     (
@@ -48,7 +255,7 @@ macro_rules! implement_dispatcher_trait {
         {
             fn dispatch<I>(iter: I) -> ( $( $container_letter, )+ )
             where
-                I: Iterator<Item = $enum_name< $( $ty_arg, )* >>,
+                I: IntoIterator<Item = $enum_name< $( $ty_arg, )* >>,
             {
                 $(
                     let mut $container_name = $container_letter::default();
@@ -73,8 +280,104 @@ macro_rules! implement_dispatcher_trait {
     }
 }
 
+/// Implements `Variants` and `VariantKind` for a given enum.
+///
+/// This macro is used by `implement_dispatch`, and should not be called by
+/// the user.
+///
+/// Each variant is described by its name, its inner type, the name of the
+/// corresponding `EitherN` variant, its index, and its shape, which is either
+/// `tuple` for variants holding a value, or `unit` for variants holding
+/// nothing, whose inner type must be `()`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __edisp_implement_variants {
+    (
+        $enum_name:ident ( $( $ty_arg:tt ),* $( , )? ),
+        $either:ident,
+        $( (
+            $variant_name:ident,
+            $inner_type:ty,
+            $either_variant:ident,
+            $idx:expr,
+            $shape:ident
+        ) ),+ $( , )?
+    ) => {
+        impl< $( $ty_arg, )* > $crate::dispatch::Variants for $enum_name< $( $ty_arg, )* > {
+            type Either = $crate::either::$either< $( $inner_type, )+ >;
+
+            fn into_either(self) -> Self::Either {
+                match self {
+                    $(
+                        $crate::__edisp_variant!(@variant $shape $enum_name $variant_name value) => {
+                            $crate::either::$either::$either_variant(
+                                $crate::__edisp_variant!(@payload $shape value),
+                            )
+                        }
+                    )+
+                }
+            }
+
+            fn from_either(either: Self::Either) -> Self {
+                match either {
+                    $(
+                        $crate::either::$either::$either_variant(
+                            $crate::__edisp_variant!(@payload $shape value),
+                        ) => $crate::__edisp_variant!(@variant $shape $enum_name $variant_name value),
+                    )+
+                }
+            }
+        }
+
+        impl< $( $ty_arg, )* > $crate::kind::VariantKind for $enum_name< $( $ty_arg, )* > {
+            const VARIANT_NAMES: &'static [&'static str] = &[ $( stringify!($variant_name), )+ ];
+
+            fn kind(&self) -> $crate::kind::VariantIndex {
+                match self {
+                    $(
+                        $crate::__edisp_variant!(@any $shape $enum_name $variant_name) => {
+                            $crate::kind::VariantIndex::new($idx)
+                        }
+                    )+
+                }
+            }
+        }
+    }
+}
+
+/// Generates the patterns and expressions used by `__edisp_implement_variants`
+/// for a variant, depending on its shape.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __edisp_variant {
+    (@variant tuple $enum_name:ident $variant_name:ident $value:ident) => {
+        $enum_name::$variant_name($value)
+    };
+    (@variant unit $enum_name:ident $variant_name:ident $value:ident) => {
+        $enum_name::$variant_name
+    };
+
+    (@payload tuple $value:ident) => {
+        $value
+    };
+    (@payload unit $value:ident) => {
+        ()
+    };
+
+    (@any tuple $enum_name:ident $variant_name:ident) => {
+        $enum_name::$variant_name(..)
+    };
+    (@any unit $enum_name:ident $variant_name:ident) => {
+        $enum_name::$variant_name
+    };
+}
+
 /// Implements the dispatch for an enum.
 ///
+/// Variants may either hold a single value, or nothing. In the latter case,
+/// a `()` is added to the corresponding container each time the variant is
+/// met.
+///
 /// ```
 /// use edisp_core::prelude::*;
 ///
@@ -88,139 +391,97 @@ macro_rules! implement_dispatcher_trait {
 /// enum MyEnum {
 ///     Integer(u8),
 ///     Other(char),
+///     Nothing,
 /// }
 ///
-/// implement_dispatch!(MyEnum, Integer(u8), Other(char));
+/// implement_dispatch!(MyEnum, Integer(u8), Other(char), Nothing);
 /// ```
 #[macro_export]
 macro_rules! implement_dispatch {
-    ($_:ident $( < $( $__:tt ),+ $( , )? > )? $( , )? ) => {
-        compile_error!("It is not necessary to implement `Dispatch` on an empty enum.");
+    // Each variant is moved from the input to the accumulator, along with
+    // the first unused `EitherN` variant and its index.
+    (
+        @munch [ $( $enum_def:tt )* ],
+        [ $( $acc:tt )* ],
+        [ ( $either_variant:ident, $idx:tt ) $( $free:tt )* ],
+        $variant_name:ident ( $inner_type:ty ) $( , $( $rest:tt )* )?
+    ) => {
+        $crate::implement_dispatch!(
+            @munch [ $( $enum_def )* ],
+            [ $( $acc )* ($variant_name, $inner_type, $either_variant, $idx, tuple), ],
+            [ $( $free )* ],
+            $( $( $rest )* )?
+        );
     };
 
-    ($_:ident $( < $( $__:tt),+ $( , )? > )?,
-     $___: ident ($____: ty) $( , )?
+    (
+        @munch [ $( $enum_def:tt )* ],
+        [ $( $acc:tt )* ],
+        [ ( $either_variant:ident, $idx:tt ) $( $free:tt )* ],
+        $variant_name:ident $( , $( $rest:tt )* )?
     ) => {
-        compile_error!("It is not necessary to implement `Dispatch` on a single-variant enum. You can use `map` and then collect instead.");
+        $crate::implement_dispatch!(
+            @munch [ $( $enum_def )* ],
+            [ $( $acc )* ($variant_name, (), $either_variant, $idx, unit), ],
+            [ $( $free )* ],
+            $( $( $rest )* )?
+        );
     };
 
+    (@munch [ $( $enum_def:tt )* ], [ $( $acc:tt )* ], [], $( $rest:tt )+) => {
+        compile_error!("Edisp can only dispatch enums with at most 8 variants.");
+    };
 
-    ($enum_name:ident $( < $( $ty_arg:tt ),+ $( , )? > )?,
-     $variant1_name: ident ($variant1_it: ty),
-     $variant2_name: ident ($variant2_it: ty) $( , )?
-    ) => {
-        implement_dispatcher_trait!(
-            $enum_name( $( $( $ty_arg, )+ )? ),
-            ($variant1_name, $variant1_it, container_a, A),
-            ($variant2_name, $variant2_it, container_b, B),
-        );
+    // Every variant has been processed, the number of unused `EitherN`
+    // variants tells which `EitherN` type must be used.
+    (@munch [ $( $enum_def:tt )* ], [ $( $acc:tt )* ], [ $( $free:tt )* ] $( , )?) => {
+        $crate::implement_dispatch!(@either [ $( $enum_def )* ], [ $( $acc )* ], [ $( $free )* ]);
     };
 
-    ($enum_name:ident $( < $( $ty_arg:tt ),+ $( , )? > )?,
-     $variant1_name: ident ($variant1_it: ty),
-     $variant2_name: ident ($variant2_it: ty),
-     $variant3_name: ident ($variant3_it: ty) $( , )?
-    ) => {
-        implement_dispatcher_trait!(
-            $enum_name( $( $( $ty_arg, )+ )? ),
-            ($variant1_name, $variant1_it, container_1, A),
-            ($variant2_name, $variant2_it, container_2, B),
-            ($variant3_name, $variant3_it, container_3, C),
-        );
+    (@either [ $( $enum_def:tt )* ], [ $( $acc:tt )* ], [(V3, 2) (V4, 3) (V5, 4) (V6, 5) (V7, 6) (V8, 7)]) => {
+        $crate::__edisp_implement_variants!($( $enum_def )*, Either2, $( $acc )*);
     };
 
-    ($enum_name:ident $( < $( $ty_arg:tt ),+ $( , )? > )?,
-     $variant1_name: ident ($variant1_it: ty),
-     $variant2_name: ident ($variant2_it: ty),
-     $variant3_name: ident ($variant3_it: ty),
-     $variant4_name: ident ($variant4_it: ty) $( , )?
-    ) => {
-        implement_dispatcher_trait!(
-            $enum_name( $( $( $ty_arg, )+ )? ),
-            ($variant1_name, $variant1_it, container_1, A),
-            ($variant2_name, $variant2_it, container_2, B),
-            ($variant3_name, $variant3_it, container_3, C),
-            ($variant4_name, $variant4_it, container_4, D),
-        );
+    (@either [ $( $enum_def:tt )* ], [ $( $acc:tt )* ], [(V4, 3) (V5, 4) (V6, 5) (V7, 6) (V8, 7)]) => {
+        $crate::__edisp_implement_variants!($( $enum_def )*, Either3, $( $acc )*);
     };
 
-    ($enum_name:ident $( < $( $ty_arg:tt ),+ $( , )? > )?,
-     $variant1_name: ident ($variant1_it: ty),
-     $variant2_name: ident ($variant2_it: ty),
-     $variant3_name: ident ($variant3_it: ty),
-     $variant4_name: ident ($variant4_it: ty),
-     $variant5_name: ident ($variant5_it: ty) $( , )?
-    ) => {
-        implement_dispatcher_trait!(
-            $enum_name( $( $( $ty_arg, )+ )? ),
-            ($variant1_name, $variant1_it, container_1, A),
-            ($variant2_name, $variant2_it, container_2, B),
-            ($variant3_name, $variant3_it, container_3, C),
-            ($variant4_name, $variant4_it, container_4, D),
-            ($variant5_name, $variant5_it, container_5, E),
-        );
+    (@either [ $( $enum_def:tt )* ], [ $( $acc:tt )* ], [(V5, 4) (V6, 5) (V7, 6) (V8, 7)]) => {
+        $crate::__edisp_implement_variants!($( $enum_def )*, Either4, $( $acc )*);
     };
 
-    ($enum_name:ident $( < $( $ty_arg:tt ),+ $( , )? > )?,
-     $variant1_name: ident ($variant1_it: ty),
-     $variant2_name: ident ($variant2_it: ty),
-     $variant3_name: ident ($variant3_it: ty),
-     $variant4_name: ident ($variant4_it: ty),
-     $variant5_name: ident ($variant5_it: ty),
-     $variant6_name: ident ($variant6_it: ty) $( , )?
-    ) => {
-        implement_dispatcher_trait!(
-            $enum_name( $( $( $ty_arg, )+ )? ),
-            ($variant1_name, $variant1_it, container_1, A),
-            ($variant2_name, $variant2_it, container_2, B),
-            ($variant3_name, $variant3_it, container_3, C),
-            ($variant4_name, $variant4_it, container_4, D),
-            ($variant5_name, $variant5_it, container_5, E),
-            ($variant6_name, $variant6_it, container_6, F),
-        );
+    (@either [ $( $enum_def:tt )* ], [ $( $acc:tt )* ], [(V6, 5) (V7, 6) (V8, 7)]) => {
+        $crate::__edisp_implement_variants!($( $enum_def )*, Either5, $( $acc )*);
     };
 
-    ($enum_name:ident $( < $( $ty_arg:tt ),+ $( , )? > )?,
-     $variant1_name: ident ($variant1_it: ty),
-     $variant2_name: ident ($variant2_it: ty),
-     $variant3_name: ident ($variant3_it: ty),
-     $variant4_name: ident ($variant4_it: ty),
-     $variant5_name: ident ($variant5_it: ty),
-     $variant6_name: ident ($variant6_it: ty),
-     $variant7_name: ident ($variant7_it: ty) $( , )?
-    ) => {
-        implement_dispatcher_trait!(
-            $enum_name( $( $( $ty_arg, )+ )? ),
-            ($variant1_name, $variant1_it, container_1, A),
-            ($variant2_name, $variant2_it, container_2, B),
-            ($variant3_name, $variant3_it, container_3, C),
-            ($variant4_name, $variant4_it, container_4, D),
-            ($variant5_name, $variant5_it, container_5, E),
-            ($variant6_name, $variant6_it, container_6, F),
-            ($variant7_name, $variant7_it, container_7, G),
-        );
+    (@either [ $( $enum_def:tt )* ], [ $( $acc:tt )* ], [(V7, 6) (V8, 7)]) => {
+        $crate::__edisp_implement_variants!($( $enum_def )*, Either6, $( $acc )*);
+    };
+
+    (@either [ $( $enum_def:tt )* ], [ $( $acc:tt )* ], [(V8, 7)]) => {
+        $crate::__edisp_implement_variants!($( $enum_def )*, Either7, $( $acc )*);
+    };
+
+    (@either [ $( $enum_def:tt )* ], [ $( $acc:tt )* ], []) => {
+        $crate::__edisp_implement_variants!($( $enum_def )*, Either8, $( $acc )*);
+    };
+
+    ($_:ident $( < $( $__:tt ),+ $( , )? > )? $( , )? ) => {
+        compile_error!("It is not necessary to implement `Dispatch` on an empty enum.");
     };
 
-    ($enum_name:ident $( < $( $ty_arg:tt ),+ $( , )? > )?,
-     $variant1_name: ident ($variant1_it: ty),
-     $variant2_name: ident ($variant2_it: ty),
-     $variant3_name: ident ($variant3_it: ty),
-     $variant4_name: ident ($variant4_it: ty),
-     $variant5_name: ident ($variant5_it: ty),
-     $variant6_name: ident ($variant6_it: ty),
-     $variant7_name: ident ($variant7_it: ty),
-     $variant8_name: ident ($variant8_it: ty) $( , )?
+    ($_:ident $( < $( $__:tt),+ $( , )? > )?,
+     $___: ident $( ($____: ty) )? $( , )?
     ) => {
-        implement_dispatcher_trait!(
-            $enum_name( $( $( $ty_arg, )+ )? ),
-            ($variant1_name, $variant1_it, container_1, A),
-            ($variant2_name, $variant2_it, container_2, B),
-            ($variant3_name, $variant3_it, container_3, C),
-            ($variant4_name, $variant4_it, container_4, D),
-            ($variant5_name, $variant5_it, container_5, E),
-            ($variant6_name, $variant6_it, container_6, F),
-            ($variant7_name, $variant7_it, container_7, G),
-            ($variant8_name, $variant8_it, container_8, H),
+        compile_error!("It is not necessary to implement `Dispatch` on a single-variant enum. You can use `map` and then collect instead.");
+    };
+
+    ($enum_name:ident $( < $( $ty_arg:tt ),+ $( , )? > )?, $( $variants:tt )+) => {
+        $crate::implement_dispatch!(
+            @munch [ $enum_name( $( $( $ty_arg, )+ )? ) ],
+            [],
+            [(V1, 0) (V2, 1) (V3, 2) (V4, 3) (V5, 4) (V6, 5) (V7, 6) (V8, 7)],
+            $( $variants )+
         );
     };
 }
@@ -298,6 +559,68 @@ mod tests {
         };
     }
 
+    #[test]
+    fn dispatch_iterator_method() {
+        use crate::prelude::*;
+
+        let i = vec![Ok(42), Err('!'), Ok(101)].into_iter();
+        let (some_oks, some_errs): (Vec<_>, String) = i.dispatch();
+
+        assert_eq!(some_oks, [42, 101]);
+        assert_eq!(some_errs, "!");
+    }
+
+    #[test]
+    fn dispatched_from_iterator() {
+        use crate::prelude::*;
+
+        let i = vec![Either3::<_, (), _>::V1(1), Either3::V3('!'), Either3::V1(2)].into_iter();
+        let dispatched: Dispatched<(Vec<_>, Vec<_>, String)> = i.collect();
+
+        assert_eq!(
+            dispatched.into_inner(),
+            (vec![1, 2], vec![], "!".to_string())
+        );
+    }
+
+    #[test]
+    fn dispatch_into_iterator() {
+        use crate::prelude::*;
+
+        let mut v = vec![Ok(42), Err('!'), Ok(101)];
+        let (some_oks, some_errs): (Vec<_>, Vec<_>) = Result::dispatch(v.drain(..1));
+        assert_eq!(some_oks, [42]);
+        assert!(some_errs.is_empty());
+
+        let (some_oks, some_errs): (Vec<_>, Vec<_>) = Result::dispatch(v);
+        assert_eq!(some_oks, [101]);
+        assert_eq!(some_errs, ['!']);
+    }
+
+    #[test]
+    fn dispatch_unit_variants() {
+        use crate::prelude::*;
+
+        #[derive(Debug, PartialEq)]
+        enum Enum {
+            Start,
+            Value(u8),
+            End,
+        }
+
+        implement_dispatch!(Enum, Start, Value(u8), End);
+
+        let i = vec![Enum::Start, Enum::Value(42), Enum::Value(101), Enum::End].into_iter();
+        let (starts, values, ends): (Vec<_>, Vec<_>, Vec<_>) = Enum::dispatch(i);
+
+        assert_eq!(starts, [()]);
+        assert_eq!(values, [42, 101]);
+        assert_eq!(ends, [()]);
+
+        assert_eq!(Enum::from_either(Enum::End.into_either()), Enum::End);
+        assert_eq!(Enum::End.variant_name(), "End");
+    }
+
     // Generates a test for a two-variants enum.
     implement_and_test_dispatching! {
         dispatch_enum2,
@@ -374,4 +697,27 @@ mod tests {
         (V7(u8), c7, Vec<_>, [101]),
         (V8(char), c8, Vec<_>, ['§']),
     }
+
+    #[test]
+    fn implement_dispatcher_trait_directly() {
+        use crate::prelude::*;
+
+        enum Enum {
+            Number(u8),
+            Letter(char),
+        }
+
+        use Enum::*;
+
+        implement_dispatcher_trait!(
+            Enum(),
+            (Number, u8, container_a, A),
+            (Letter, char, container_b, B),
+        );
+
+        let (numbers, letters): (Vec<_>, String) = Enum::dispatch(vec![Number(1), Letter('a')]);
+
+        assert_eq!(numbers, [1]);
+        assert_eq!(letters, "a");
+    }
 }
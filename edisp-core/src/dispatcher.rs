@@ -0,0 +1,274 @@
+//! Push-based dispatching.
+//!
+//! Values to dispatch are not always available as a single iterator: they may
+//! come from callbacks, or in several batches. This module contains the
+//! `Dispatcher` type, which holds containers and dispatches values as they
+//! are pushed, the `Parts` type, which allows to dispatch values with
+//! `Extend`, and the `DispatchInto` trait on which they are built.
+
+use core::fmt;
+use core::iter::FromIterator;
+use core::marker::PhantomData;
+
+use crate::{dispatch::Variants, either::*};
+
+/// Dispatches a single value in existing containers.
+///
+/// The generic type `O` should be a tuple whose arity is equal to the number
+/// of variants of the implementor, and should contain only types which
+/// implement `Extend`.
+///
+/// ```
+/// use edisp_core::prelude::*;
+///
+/// let mut containers = (Vec::new(), Vec::new());
+/// Ok::<_, &str>(42).dispatch_into(&mut containers);
+/// Err::<i32, _>("foo").dispatch_into(&mut containers);
+///
+/// assert_eq!(containers, (vec![42], vec!["foo"]));
+/// ```
+pub trait DispatchInto<O> {
+    /// Adds the value in the container matching its variant.
+    fn dispatch_into(self, containers: &mut O);
+}
+
+/// Implements `DispatchInto` for every enum with a given number of variants.
+macro_rules! implement_dispatch_into_for_arity {
+    (
+        $either:ident,
+        $arity:literal,
+        $( (
+            $variant:ident,
+            $t:ident,
+            $c:ident,
+            $c_name:ident,
+            $f:ident,
+            $f_name:ident,
+            $idx:tt
+        ) ),+ $( , )?
+    ) => {
+        impl<E, $( $t, )+ $( $c, )+> DispatchInto<( $( $c, )+ )> for E
+        where
+            E: Variants<Either = $either< $( $t, )+ >>,
+            $( $c: Extend<$t>, )+
+        {
+            fn dispatch_into(self, containers: &mut ( $( $c, )+ )) {
+                match self.into_either() {
+                    $( $either::$variant(value) => containers.$idx.extend(Some(value)), )+
+                }
+            }
+        }
+    };
+}
+
+for_each_arity!(implement_dispatch_into_for_arity);
+
+/// A push-based dispatcher.
+///
+/// A `Dispatcher` owns a tuple of containers, in which values of type `E` are
+/// dispatched as they are pushed. Containers are given back by `finish`.
+///
+/// ```
+/// use edisp_core::prelude::*;
+///
+/// let mut dispatcher = Dispatcher::<Result<_, _>, (Vec<_>, Vec<_>)>::new();
+///
+/// dispatcher.push(Ok(1));
+/// dispatcher.push_iter(vec![Err("foo"), Ok(2)]);
+///
+/// let (some_oks, some_errs) = dispatcher.finish();
+/// assert_eq!(some_oks, [1, 2]);
+/// assert_eq!(some_errs, ["foo"]);
+/// ```
+pub struct Dispatcher<E, O> {
+    containers: O,
+    marker: PhantomData<fn(E)>,
+}
+
+impl<E, O: fmt::Debug> fmt::Debug for Dispatcher<E, O> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Dispatcher")
+            .field("containers", &self.containers)
+            .finish()
+    }
+}
+
+impl<E, O> Dispatcher<E, O>
+where
+    E: DispatchInto<O>,
+{
+    /// Creates a dispatcher with empty containers.
+    pub fn new() -> Dispatcher<E, O>
+    where
+        O: Default,
+    {
+        Dispatcher::from_containers(O::default())
+    }
+
+    /// Creates a dispatcher which dispatches in existing containers.
+    pub fn from_containers(containers: O) -> Dispatcher<E, O> {
+        Dispatcher {
+            containers,
+            marker: PhantomData,
+        }
+    }
+
+    /// Dispatches a value.
+    pub fn push(&mut self, value: E) {
+        value.dispatch_into(&mut self.containers);
+    }
+
+    /// Dispatches every value yielded by an iterator.
+    pub fn push_iter<I: IntoIterator<Item = E>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+
+    /// Returns a reference to the containers.
+    pub fn containers(&self) -> &O {
+        &self.containers
+    }
+
+    /// Stops dispatching and returns the containers.
+    pub fn finish(self) -> O {
+        self.containers
+    }
+}
+
+impl<E, O> Default for Dispatcher<E, O>
+where
+    E: DispatchInto<O>,
+    O: Default,
+{
+    fn default() -> Dispatcher<E, O> {
+        Dispatcher::new()
+    }
+}
+
+impl<E, O> Extend<E> for Dispatcher<E, O>
+where
+    E: DispatchInto<O>,
+{
+    fn extend<I: IntoIterator<Item = E>>(&mut self, iter: I) {
+        self.push_iter(iter);
+    }
+}
+
+/// Creates a dispatcher with empty containers, and dispatches the values of
+/// an iterator in them.
+///
+/// ```
+/// use edisp_core::prelude::*;
+///
+/// let iter = vec![Ok(1), Err("foo")].into_iter();
+/// let mut dispatcher: Dispatcher<_, (Vec<_>, Vec<_>)> = iter.collect();
+/// dispatcher.extend(vec![Ok(2)]);
+///
+/// assert_eq!(dispatcher.finish(), (vec![1, 2], vec!["foo"]));
+/// ```
+impl<E, O> FromIterator<E> for Dispatcher<E, O>
+where
+    E: DispatchInto<O>,
+    O: Default,
+{
+    fn from_iter<I: IntoIterator<Item = E>>(iter: I) -> Dispatcher<E, O> {
+        let mut dispatcher = Dispatcher::new();
+        dispatcher.push_iter(iter);
+        dispatcher
+    }
+}
+
+/// A tuple of containers which can be extended with enum values.
+///
+/// `Parts` implements `Extend` for every enum which can be dispatched in its
+/// inner containers. This allows to use it with any API expecting an
+/// `Extend` sink, including as a container for another dispatch.
+///
+/// ```
+/// use edisp_core::prelude::*;
+///
+/// let mut parts = Parts::<(Vec<_>, Vec<_>)>::default();
+/// parts.extend(vec![Ok(1), Err("foo")]);
+/// parts.extend(vec![Ok::<_, &str>(2)]);
+///
+/// let (some_oks, some_errs) = parts.into_inner();
+/// assert_eq!(some_oks, [1, 2]);
+/// assert_eq!(some_errs, ["foo"]);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Parts<O>(pub O);
+
+impl<O> Parts<O> {
+    /// Returns the inner containers.
+    pub fn into_inner(self) -> O {
+        self.0
+    }
+}
+
+impl<E, O> Extend<E> for Parts<O>
+where
+    E: DispatchInto<O>,
+{
+    fn extend<I: IntoIterator<Item = E>>(&mut self, iter: I) {
+        for value in iter {
+            value.dispatch_into(&mut self.0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dispatch::Dispatch;
+
+    #[test]
+    fn dispatcher_across_batches() {
+        let mut dispatcher = Dispatcher::from_containers((vec![0], String::new()));
+
+        for batch in [
+            vec![Either2::V1(1), Either2::V2('a')],
+            vec![Either2::V2('b')],
+        ] {
+            dispatcher.push_iter(batch);
+        }
+        assert_eq!(dispatcher.containers().0, [0, 1]);
+
+        dispatcher.push(Either2::V1(2));
+        assert_eq!(dispatcher.finish(), (vec![0, 1, 2], "ab".to_string()));
+    }
+
+    #[test]
+    fn dispatcher_debug_without_debug_enum() {
+        struct NotDebug;
+
+        let dispatcher = Dispatcher::<Either2<NotDebug, u8>, _>::from_containers((
+            crate::containers::Count::default(),
+            vec![42],
+        ));
+
+        assert_eq!(
+            format!("{:?}", dispatcher),
+            format!("Dispatcher {{ containers: {:?} }}", dispatcher.containers()),
+        );
+    }
+
+    #[test]
+    fn dispatcher_as_extend_sink() {
+        let mut dispatcher = Dispatcher::<_, (Vec<_>, String)>::new();
+        dispatcher.extend((1..=3).map(|n| if n == 2 { Err('!') } else { Ok(n) }));
+
+        assert_eq!(dispatcher.finish(), (vec![1, 3], "!".to_string()));
+    }
+
+    #[test]
+    fn parts_as_nested_container() {
+        let i = vec![Ok(Ok(1)), Err('!'), Ok(Err("foo")), Ok(Ok(2))].into_iter();
+        let (Parts((some_oks, some_errs)), some_chars): (Parts<(Vec<_>, Vec<_>)>, Vec<_>) =
+            Result::dispatch(i);
+
+        assert_eq!(some_oks, [1, 2]);
+        assert_eq!(some_errs, ["foo"]);
+        assert_eq!(some_chars, ['!']);
+    }
+}
@@ -0,0 +1,80 @@
+//! Dispatching the content of a collection in place.
+//!
+//! Moving a collection into an iterator gives up its allocation. This module
+//! contains the `DispatchDrain` trait, which dispatches the content of a
+//! collection through its draining iterator, leaving it empty but keeping
+//! its capacity, so that it can be refilled.
+
+use alloc::collections::{BinaryHeap, VecDeque};
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::hash::{BuildHasher, Hash};
+#[cfg(feature = "std")]
+use std::collections::HashSet;
+
+use crate::dispatch::Dispatch;
+
+/// Allows to dispatch the content of a collection, leaving it empty.
+///
+/// ```
+/// use edisp_core::prelude::*;
+///
+/// let mut values = vec![Ok(1), Err("foo"), Ok(2)];
+/// let (some_oks, some_errs): (Vec<_>, Vec<_>) = values.dispatch_drain();
+///
+/// assert_eq!(some_oks, [1, 2]);
+/// assert_eq!(some_errs, ["foo"]);
+/// assert!(values.is_empty());
+/// ```
+pub trait DispatchDrain<E> {
+    /// Dispatches every value of the collection, and empties it.
+    fn dispatch_drain<O>(&mut self) -> O
+    where
+        E: Dispatch<O>;
+}
+
+/// Implements `DispatchDrain` for a collection which has a `drain` method.
+macro_rules! implement_dispatch_drain {
+    ( $( [ $( $generics:tt )* ] $collection:ty => ( $( $range:tt )* ) ),+ $( , )? ) => {
+        $(
+            impl< $( $generics )* > DispatchDrain<E> for $collection {
+                fn dispatch_drain<O>(&mut self) -> O
+                where
+                    E: Dispatch<O>,
+                {
+                    E::dispatch(self.drain( $( $range )* ))
+                }
+            }
+        )+
+    };
+}
+
+implement_dispatch_drain! {
+    [E] Vec<E> => (..),
+    [E] VecDeque<E> => (..),
+    [E: Ord] BinaryHeap<E> => (),
+}
+
+#[cfg(feature = "std")]
+implement_dispatch_drain! {
+    [E: Eq + Hash, S: BuildHasher] HashSet<E, S> => (),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::either::Either2;
+
+    #[test]
+    fn dispatch_drain_keeps_capacity() {
+        let mut values = VecDeque::with_capacity(16);
+        values.extend(vec![Either2::V2('a'), Either2::V1(42), Either2::V2('b')]);
+
+        let (some_v1, some_v2): (Vec<_>, String) = values.dispatch_drain();
+
+        assert_eq!(some_v1, [42]);
+        assert_eq!(some_v2, "ab");
+        assert!(values.is_empty());
+        assert!(values.capacity() >= 16);
+    }
+}
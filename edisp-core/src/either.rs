@@ -0,0 +1,296 @@
+//! Generic *n*-variants enums.
+//!
+//! This module contains the `Either2`, `Either3`, ..., `Either8` enums. They
+//! are the canonical representation of an enum with a given number of
+//! variants: every enum implementing [`Variants`] can be converted in the
+//! `EitherN` type which has the same number of variants, and every
+//! dispatching flavor is implemented once per `EitherN` type.
+//!
+//! They can also be dispatched themselves, which allows to perform ad hoc
+//! multi-way splits without declaring a dedicated enum:
+//!
+//! ```
+//! use edisp_core::prelude::*;
+//!
+//! let words = vec!["manatee", "42", "", "horse", "101"];
+//!
+//! let (empty, numbers, others): (Vec<_>, Vec<_>, Vec<_>) =
+//!     dispatch_partition_map(words, |word| {
+//!         if word.is_empty() {
+//!             Either3::V1(())
+//!         } else if let Ok(n) = word.parse::<u32>() {
+//!             Either3::V2(n)
+//!         } else {
+//!             Either3::V3(word)
+//!         }
+//!     });
+//!
+//! assert_eq!(empty, [()]);
+//! assert_eq!(numbers, [42, 101]);
+//! assert_eq!(others, ["manatee", "horse"]);
+//! ```
+//!
+//! [`Variants`]: ../dispatch/trait.Variants.html
+
+use crate::{
+    dispatch::Variants,
+    kind::{VariantIndex, VariantKind},
+};
+
+/// Calls a macro once for each supported enum arity.
+///
+/// The called macro receives the name of the `EitherN` type, its arity, and
+/// for each variant:
+///   - the name of the `EitherN` variant,
+///   - a type parameter name for the variant's inner type,
+///   - a type parameter name for the variant's container,
+///   - a variable name for the variant's container,
+///   - a type parameter name for a closure handling the variant,
+///   - a variable name for a closure handling the variant,
+///   - the index of the variant, which can be used to access tuple fields.
+///
+/// This macro is meant to be used internally to implement traits for every
+/// arity supported by `edisp`.
+macro_rules! for_each_arity {
+    ($m:ident) => {
+        $m!(
+            Either2,
+            2,
+            (V1, T1, C1, c1, F1, f1, 0),
+            (V2, T2, C2, c2, F2, f2, 1)
+        );
+
+        $m!(
+            Either3,
+            3,
+            (V1, T1, C1, c1, F1, f1, 0),
+            (V2, T2, C2, c2, F2, f2, 1),
+            (V3, T3, C3, c3, F3, f3, 2),
+        );
+
+        $m!(
+            Either4,
+            4,
+            (V1, T1, C1, c1, F1, f1, 0),
+            (V2, T2, C2, c2, F2, f2, 1),
+            (V3, T3, C3, c3, F3, f3, 2),
+            (V4, T4, C4, c4, F4, f4, 3),
+        );
+
+        $m!(
+            Either5,
+            5,
+            (V1, T1, C1, c1, F1, f1, 0),
+            (V2, T2, C2, c2, F2, f2, 1),
+            (V3, T3, C3, c3, F3, f3, 2),
+            (V4, T4, C4, c4, F4, f4, 3),
+            (V5, T5, C5, c5, F5, f5, 4),
+        );
+
+        $m!(
+            Either6,
+            6,
+            (V1, T1, C1, c1, F1, f1, 0),
+            (V2, T2, C2, c2, F2, f2, 1),
+            (V3, T3, C3, c3, F3, f3, 2),
+            (V4, T4, C4, c4, F4, f4, 3),
+            (V5, T5, C5, c5, F5, f5, 4),
+            (V6, T6, C6, c6, F6, f6, 5),
+        );
+
+        $m!(
+            Either7,
+            7,
+            (V1, T1, C1, c1, F1, f1, 0),
+            (V2, T2, C2, c2, F2, f2, 1),
+            (V3, T3, C3, c3, F3, f3, 2),
+            (V4, T4, C4, c4, F4, f4, 3),
+            (V5, T5, C5, c5, F5, f5, 4),
+            (V6, T6, C6, c6, F6, f6, 5),
+            (V7, T7, C7, c7, F7, f7, 6),
+        );
+
+        $m!(
+            Either8,
+            8,
+            (V1, T1, C1, c1, F1, f1, 0),
+            (V2, T2, C2, c2, F2, f2, 1),
+            (V3, T3, C3, c3, F3, f3, 2),
+            (V4, T4, C4, c4, F4, f4, 3),
+            (V5, T5, C5, c5, F5, f5, 4),
+            (V6, T6, C6, c6, F6, f6, 5),
+            (V7, T7, C7, c7, F7, f7, 6),
+            (V8, T8, C8, c8, F8, f8, 7),
+        );
+    };
+}
+
+pub(crate) use for_each_arity;
+
+/// Declares an `EitherN` enum.
+macro_rules! declare_either {
+    (
+        $either:ident,
+        $arity:literal,
+        $( (
+            $variant:ident,
+            $t:ident,
+            $c:ident,
+            $c_name:ident,
+            $f:ident,
+            $f_name:ident,
+            $idx:tt
+        ) ),+ $( , )?
+    ) => {
+        #[doc = concat!("A generic enum with ", stringify!($arity), " variants.")]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub enum $either< $( $t, )+ > {
+            $(
+                #[doc = concat!("Holds a value of type `", stringify!($t), "`.")]
+                $variant($t),
+            )+
+        }
+    };
+}
+
+for_each_arity!(declare_either);
+
+/// Properties shared by every `EitherN` type.
+pub trait EitherN {
+    /// The number of variants.
+    const ARITY: usize;
+
+    /// An array of `ARITY` counters, one for each variant.
+    type Counts: Default + AsRef<[usize]> + AsMut<[usize]>;
+
+    /// An array of `ARITY` flags, one for each variant.
+    type Flags: Copy + Default + AsRef<[bool]> + AsMut<[bool]>;
+
+    /// Returns the zero-based index of the variant.
+    fn index(&self) -> usize;
+}
+
+/// Implements `EitherN` for an `EitherN` type.
+macro_rules! implement_either_n {
+    (
+        $either:ident,
+        $arity:literal,
+        $( (
+            $variant:ident,
+            $t:ident,
+            $c:ident,
+            $c_name:ident,
+            $f:ident,
+            $f_name:ident,
+            $idx:tt
+        ) ),+ $( , )?
+    ) => {
+        impl< $( $t, )+ > EitherN for $either< $( $t, )+ > {
+            const ARITY: usize = $arity;
+
+            type Counts = [usize; $arity];
+
+            type Flags = [bool; $arity];
+
+            fn index(&self) -> usize {
+                match self {
+                    $( $either::$variant(_) => $idx, )+
+                }
+            }
+        }
+    };
+}
+
+for_each_arity!(implement_either_n);
+
+/// Implements `Variants` for an `EitherN` type.
+///
+/// An `EitherN` type is its own representation.
+macro_rules! implement_variants_for_either {
+    (
+        $either:ident,
+        $arity:literal,
+        $( (
+            $variant:ident,
+            $t:ident,
+            $c:ident,
+            $c_name:ident,
+            $f:ident,
+            $f_name:ident,
+            $idx:tt
+        ) ),+ $( , )?
+    ) => {
+        impl< $( $t, )+ > Variants for $either< $( $t, )+ > {
+            type Either = Self;
+
+            fn into_either(self) -> Self {
+                self
+            }
+
+            fn from_either(either: Self) -> Self {
+                either
+            }
+        }
+    };
+}
+
+for_each_arity!(implement_variants_for_either);
+
+/// Implements `VariantKind` for an `EitherN` type.
+macro_rules! implement_variant_kind_for_either {
+    (
+        $either:ident,
+        $arity:literal,
+        $( (
+            $variant:ident,
+            $t:ident,
+            $c:ident,
+            $c_name:ident,
+            $f:ident,
+            $f_name:ident,
+            $idx:tt
+        ) ),+ $( , )?
+    ) => {
+        impl< $( $t, )+ > VariantKind for $either< $( $t, )+ > {
+            const VARIANT_NAMES: &'static [&'static str] = &[ $( stringify!($variant), )+ ];
+
+            fn kind(&self) -> VariantIndex {
+                match self {
+                    $( $either::$variant(_) => VariantIndex::new($idx), )+
+                }
+            }
+        }
+    };
+}
+
+for_each_arity!(implement_variant_kind_for_either);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn dispatch_either2() {
+        let i = vec![Either2::V1(42), Either2::V2('!'), Either2::V1(101)].into_iter();
+        let (some_v1, some_v2): (Vec<_>, Vec<_>) = Either2::dispatch(i);
+
+        assert_eq!(some_v1, [42, 101]);
+        assert_eq!(some_v2, ['!']);
+    }
+
+    #[test]
+    fn dispatch_either8() {
+        type E = Either8<u8, u16, u32, u64, i8, i16, i32, i64>;
+
+        let i: Vec<E> = vec![
+            Either8::V8(-8),
+            Either8::V1(1),
+            Either8::V5(-5),
+            Either8::V3(3),
+        ];
+        let counts = E::dispatch_counts(i);
+
+        assert_eq!(counts, [1, 0, 1, 0, 1, 0, 0, 1]);
+    }
+}
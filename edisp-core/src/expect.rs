@@ -0,0 +1,201 @@
+//! Checking the number of values of each variant.
+//!
+//! Tests and data-validation jobs often know how many values of each variant
+//! should be met. This module contains the `DispatchExpecting` trait, which
+//! dispatches values and then checks these numbers, reporting every
+//! expectation which is not met.
+
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use crate::{
+    dispatch::{Dispatch, Variants},
+    either::EitherN,
+    kind::VariantIndex,
+};
+
+/// The expected number of values of a variant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Expectation {
+    /// Any number of values.
+    Any,
+    /// Exactly the given number of values.
+    Exactly(usize),
+    /// At least the given number of values.
+    AtLeast(usize),
+    /// At most the given number of values.
+    AtMost(usize),
+}
+
+impl Expectation {
+    /// Returns whether a number of values meets the expectation.
+    pub fn is_met_by(self, count: usize) -> bool {
+        match self {
+            Expectation::Any => true,
+            Expectation::Exactly(expected) => count == expected,
+            Expectation::AtLeast(min) => count >= min,
+            Expectation::AtMost(max) => count <= max,
+        }
+    }
+}
+
+/// An expectation which is not met.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Violation {
+    /// The variant.
+    pub variant: VariantIndex,
+    /// The expected number of values.
+    pub expected: Expectation,
+    /// The number of values which were dispatched.
+    pub actual: usize,
+}
+
+/// The error returned when some expectations are not met.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct UnmetExpectations {
+    /// Every expectation which is not met, ordered by variant.
+    pub violations: Vec<Violation>,
+}
+
+impl fmt::Display for UnmetExpectations {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unexpected number of values for variant")?;
+
+        for (i, violation) in self.violations.iter().enumerate() {
+            let separator = if i == 0 { " " } else { ", " };
+            write!(
+                f,
+                "{}{} (expected {:?}, got {})",
+                separator,
+                violation.variant.get(),
+                violation.expected,
+                violation.actual,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for UnmetExpectations {}
+
+/// A dispatcher trait checking the number of values of each variant.
+///
+/// This trait behaves like `Dispatch`, and then checks the number of values
+/// of each variant against `expectations`, which must contain one
+/// `Expectation` per variant.
+///
+/// ```
+/// use edisp_core::prelude::*;
+///
+/// let iter = vec![Ok(1), Err("foo"), Ok(2)].into_iter();
+/// let checked: Result<(Vec<_>, Vec<_>), _> =
+///     Result::dispatch_expecting(iter, [Expectation::AtLeast(1), Expectation::Exactly(0)]);
+///
+/// let violations = checked.unwrap_err().violations;
+/// assert_eq!(
+///     violations,
+///     [Violation {
+///         variant: VariantIndex::new(1),
+///         expected: Expectation::Exactly(0),
+///         actual: 1,
+///     }],
+/// );
+/// ```
+pub trait DispatchExpecting<O>
+where
+    Self: Sized,
+{
+    /// Performs dispatching, and checks the number of values of each
+    /// variant.
+    ///
+    /// # Errors
+    ///
+    /// This function returns every expectation which is not met. The iterator
+    /// is consumed entirely in any case.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the number of expectations is not equal to
+    /// the number of variants.
+    fn dispatch_expecting<I, X>(iter: I, expectations: X) -> Result<O, UnmetExpectations>
+    where
+        I: IntoIterator<Item = Self>,
+        X: AsRef<[Expectation]>;
+}
+
+impl<E, O> DispatchExpecting<O> for E
+where
+    E: Dispatch<O> + Variants,
+    E::Either: EitherN,
+{
+    fn dispatch_expecting<I, X>(iter: I, expectations: X) -> Result<O, UnmetExpectations>
+    where
+        I: IntoIterator<Item = Self>,
+        X: AsRef<[Expectation]>,
+    {
+        let expectations = expectations.as_ref();
+        assert_eq!(
+            expectations.len(),
+            <E::Either as EitherN>::ARITY,
+            "there must be one expectation per variant",
+        );
+
+        let mut counts = <E::Either as EitherN>::Counts::default();
+        let containers = E::dispatch(iter.into_iter().map(|element| {
+            let either = element.into_either();
+            counts.as_mut()[either.index()] += 1;
+            E::from_either(either)
+        }));
+
+        let violations = expectations
+            .iter()
+            .zip(counts.as_ref())
+            .enumerate()
+            .filter(|(_, (expected, actual))| !expected.is_met_by(**actual))
+            .map(|(variant, (expected, actual))| Violation {
+                variant: VariantIndex::new(variant),
+                expected: *expected,
+                actual: *actual,
+            })
+            .collect::<Vec<_>>();
+
+        if violations.is_empty() {
+            Ok(containers)
+        } else {
+            Err(UnmetExpectations { violations })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dispatch_expecting_met() {
+        let i = vec![Ok(42), Err('!'), Ok(101)];
+        let checked: Result<(Vec<_>, String), _> =
+            Result::dispatch_expecting(i, [Expectation::Exactly(2), Expectation::AtMost(1)]);
+
+        assert_eq!(checked, Ok((vec![42, 101], "!".to_string())));
+    }
+
+    #[test]
+    fn dispatch_expecting_reports_every_violation() {
+        let i = vec![Ok::<_, ()>(42), Ok(101)];
+        let checked: Result<(Vec<_>, Vec<_>), _> =
+            Result::dispatch_expecting(i, vec![Expectation::AtMost(1), Expectation::AtLeast(1)]);
+        let error = checked.unwrap_err();
+
+        assert_eq!(error.violations.len(), 2);
+        assert_eq!(
+            error.to_string(),
+            "unexpected number of values for variant 0 (expected AtMost(1), got 2), \
+             1 (expected AtLeast(1), got 0)",
+        );
+    }
+}
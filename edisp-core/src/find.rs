@@ -0,0 +1,107 @@
+//! Finding the first value of each variant.
+//!
+//! When scanning configuration entries or headers, only the first value of
+//! each kind may matter. This module contains the `FindEach` trait, which
+//! stops consuming the iterator as soon as every variant has been met.
+
+use crate::{dispatch::Variants, either::*};
+
+/// A trait returning the first value of each variant.
+///
+/// The generic type `O` is a tuple of `Option`s, one for each variant of the
+/// implementor. The iterator is consumed until every variant has been met
+/// once, or until it is exhausted.
+///
+/// ```
+/// use edisp_core::prelude::*;
+///
+/// let mut iter = vec![Ok(1), Ok(2), Err("foo"), Err("bar")].into_iter();
+/// let (first_ok, first_err) = Result::find_each(iter.by_ref());
+///
+/// assert_eq!(first_ok, Some(1));
+/// assert_eq!(first_err, Some("foo"));
+/// assert_eq!(iter.next(), Some(Err("bar")));
+/// ```
+pub trait FindEach<O>
+where
+    Self: Sized,
+{
+    /// Returns the first value of each variant.
+    fn find_each<I: IntoIterator<Item = Self>>(iter: I) -> O;
+}
+
+/// Implements `FindEach` for every enum with a given number of variants.
+macro_rules! implement_find_each_for_arity {
+    (
+        $either:ident,
+        $arity:literal,
+        $( (
+            $variant:ident,
+            $t:ident,
+            $c:ident,
+            $c_name:ident,
+            $f:ident,
+            $f_name:ident,
+            $idx:tt
+        ) ),+ $( , )?
+    ) => {
+        impl<E, $( $t, )+> FindEach<( $( Option<$t>, )+ )> for E
+        where
+            E: Variants<Either = $either< $( $t, )+ >>,
+        {
+            fn find_each<I: IntoIterator<Item = Self>>(iter: I) -> ( $( Option<$t>, )+ ) {
+                $( let mut $c_name = None; )+
+
+                for element in iter {
+                    match element.into_either() {
+                        $(
+                            $either::$variant(value) => {
+                                if $c_name.is_none() {
+                                    $c_name = Some(value);
+                                }
+                            }
+                        )+
+                    }
+
+                    if $( $c_name.is_some() )&&+ {
+                        break;
+                    }
+                }
+
+                ( $( $c_name, )+ )
+            }
+        }
+    };
+}
+
+for_each_arity!(implement_find_each_for_arity);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_each_missing_variant() {
+        let i = vec![Ok(42), Ok(101)].into_iter();
+        let (first_ok, first_err): (_, Option<()>) = Result::find_each(i);
+
+        assert_eq!(first_ok, Some(42));
+        assert_eq!(first_err, None);
+    }
+
+    #[test]
+    fn find_each_stops_early() {
+        let mut i = vec![
+            Either3::V2('a'),
+            Either3::V1(0),
+            Either3::V2('b'),
+            Either3::V3("foo"),
+            Either3::V1(1),
+        ]
+        .into_iter();
+        let found = Either3::find_each(i.by_ref());
+
+        assert_eq!(found, (Some(0), Some('a'), Some("foo")));
+        assert_eq!(i.next(), Some(Either3::V1(1)));
+    }
+}
@@ -0,0 +1,186 @@
+//! Folding dispatched values.
+//!
+//! Collecting values in containers is not always needed: a sum, a maximum or
+//! a joined string can be computed on the fly. This module contains the
+//! `DispatchFold` trait, which folds the values of each variant with their
+//! own accumulator and closure, in a single pass, and the `DispatchForEach`
+//! trait, which calls a closure on each value.
+
+use crate::{dispatch::Variants, either::*};
+
+/// A dispatcher trait which folds values.
+///
+/// The generic type `F` should be a tuple whose arity is equal to the number
+/// of variants of the implementor. Each of its elements is a pair containing
+/// the initial value of the accumulator and the closure folding the values of
+/// the corresponding variant.
+///
+/// ```
+/// use edisp_core::prelude::*;
+///
+/// let iter = vec![Ok(1), Err("foo"), Ok(2), Err("bar")].into_iter();
+/// let (sum, joined) = Result::dispatch_fold(
+///     iter,
+///     (
+///         (0, |sum: i32, value: i32| sum + value),
+///         (String::new(), |joined: String, value: &str| joined + value),
+///     ),
+/// );
+///
+/// assert_eq!(sum, 3);
+/// assert_eq!(joined, "foobar");
+/// ```
+pub trait DispatchFold<F>
+where
+    Self: Sized,
+{
+    /// The final value of each accumulator.
+    type Output;
+
+    /// Performs folding.
+    fn dispatch_fold<I: IntoIterator<Item = Self>>(iter: I, folds: F) -> Self::Output;
+}
+
+/// Implements `DispatchFold` for every enum with a given number of variants.
+macro_rules! implement_dispatch_fold_for_arity {
+    (
+        $either:ident,
+        $arity:literal,
+        $( (
+            $variant:ident,
+            $t:ident,
+            $c:ident,
+            $c_name:ident,
+            $f:ident,
+            $f_name:ident,
+            $idx:tt
+        ) ),+ $( , )?
+    ) => {
+        impl<E, $( $t, )+ $( $c, )+ $( $f, )+> DispatchFold<( $( ($c, $f), )+ )> for E
+        where
+            E: Variants<Either = $either< $( $t, )+ >>,
+            $( $f: FnMut($c, $t) -> $c, )+
+        {
+            type Output = ( $( $c, )+ );
+
+            fn dispatch_fold<I: IntoIterator<Item = Self>>(
+                iter: I,
+                folds: ( $( ($c, $f), )+ ),
+            ) -> Self::Output {
+                let ( $( (mut $c_name, mut $f_name), )+ ) = folds;
+
+                for element in iter {
+                    match element.into_either() {
+                        $(
+                            $either::$variant(value) => $c_name = $f_name($c_name, value),
+                        )+
+                    }
+                }
+
+                ( $( $c_name, )+ )
+            }
+        }
+    };
+}
+
+for_each_arity!(implement_dispatch_fold_for_arity);
+
+/// A dispatcher trait which calls a closure on each value.
+///
+/// The generic type `F` should be a tuple whose arity is equal to the number
+/// of variants of the implementor. Each of its elements is the closure called
+/// on the values of the corresponding variant. Nothing is allocated.
+///
+/// ```
+/// use edisp_core::prelude::*;
+///
+/// let mut log = Vec::new();
+/// let mut errors = Vec::new();
+///
+/// let iter = vec![Ok(1), Err("foo"), Ok(2)].into_iter();
+/// Result::dispatch_for_each(
+///     iter,
+///     (
+///         |value: i32| log.push(format!("success: {}", value)),
+///         |error: &str| errors.push(format!("failure: {}", error)),
+///     ),
+/// );
+///
+/// assert_eq!(log, ["success: 1", "success: 2"]);
+/// assert_eq!(errors, ["failure: foo"]);
+/// ```
+pub trait DispatchForEach<F>
+where
+    Self: Sized,
+{
+    /// Calls the closure corresponding to each value.
+    fn dispatch_for_each<I: IntoIterator<Item = Self>>(iter: I, callbacks: F);
+}
+
+/// Implements `DispatchForEach` for every enum with a given number of
+/// variants.
+macro_rules! implement_dispatch_for_each_for_arity {
+    (
+        $either:ident,
+        $arity:literal,
+        $( (
+            $variant:ident,
+            $t:ident,
+            $c:ident,
+            $c_name:ident,
+            $f:ident,
+            $f_name:ident,
+            $idx:tt
+        ) ),+ $( , )?
+    ) => {
+        impl<E, $( $t, )+ $( $f, )+> DispatchForEach<( $( $f, )+ )> for E
+        where
+            E: Variants<Either = $either< $( $t, )+ >>,
+            $( $f: FnMut($t), )+
+        {
+            fn dispatch_for_each<I: IntoIterator<Item = Self>>(iter: I, callbacks: ( $( $f, )+ )) {
+                let ( $( mut $f_name, )+ ) = callbacks;
+
+                for element in iter {
+                    match element.into_either() {
+                        $( $either::$variant(value) => $f_name(value), )+
+                    }
+                }
+            }
+        }
+    };
+}
+
+for_each_arity!(implement_dispatch_for_each_for_arity);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dispatch_fold_folds_each_variant() {
+        let i = vec![Ok(42), Err(3), Ok(101), Err(7)].into_iter();
+        let (max, product) = Result::dispatch_fold(
+            i,
+            (
+                (0, |max: i32, value: i32| max.max(value)),
+                (1, |product: i32, value: i32| product * value),
+            ),
+        );
+
+        assert_eq!(max, 101);
+        assert_eq!(product, 21);
+    }
+
+    #[test]
+    fn dispatch_for_each_calls_each_closure() {
+        let i = vec![Ok(42), Err("foo"), Ok(101)].into_iter();
+        let mut oks = 0;
+        let mut errs = Vec::new();
+
+        Result::dispatch_for_each(i, (|value| oks += value, |error| errs.push(error)));
+
+        assert_eq!(oks, 143);
+        assert_eq!(errs, ["foo"]);
+    }
+}
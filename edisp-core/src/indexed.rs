@@ -0,0 +1,163 @@
+//! Dispatching with original positions or context.
+//!
+//! Dispatching loses the relative order of values which end up in different
+//! containers. This module contains the `DispatchWithIndices` trait, which
+//! stores the position of each value in the original iterator alongside the
+//! value, so that this order can be recovered later, and the
+//! `DispatchWithContext` trait, which carries any context, such as line
+//! numbers or request identifiers, alongside the values.
+
+use crate::{dispatch::Variants, either::*};
+
+/// A dispatcher trait keeping track of positions.
+///
+/// This trait behaves like `Dispatch`, except that each container receives
+/// `(index, value)` pairs, `index` being the position of the value in the
+/// dispatched iterator.
+///
+/// ```
+/// use edisp_core::prelude::*;
+///
+/// let iter = vec![Ok(1), Err("foo"), Ok(2)].into_iter();
+/// let (some_oks, some_errs): (Vec<_>, Vec<_>) = Result::dispatch_with_indices(iter);
+///
+/// assert_eq!(some_oks, [(0, 1), (2, 2)]);
+/// assert_eq!(some_errs, [(1, "foo")]);
+/// ```
+pub trait DispatchWithIndices<O>
+where
+    Self: Sized,
+{
+    /// Performs dispatching, pairing each value with its position.
+    fn dispatch_with_indices<I: IntoIterator<Item = Self>>(iter: I) -> O;
+}
+
+/// Implements `DispatchWithIndices` for every enum with a given number of
+/// variants.
+macro_rules! implement_dispatch_with_indices_for_arity {
+    (
+        $either:ident,
+        $arity:literal,
+        $( (
+            $variant:ident,
+            $t:ident,
+            $c:ident,
+            $c_name:ident,
+            $f:ident,
+            $f_name:ident,
+            $idx:tt
+        ) ),+ $( , )?
+    ) => {
+        impl<E, $( $t, )+ $( $c, )+> DispatchWithIndices<( $( $c, )+ )> for E
+        where
+            E: Variants<Either = $either< $( $t, )+ >>,
+            $( $c: Default + Extend<(usize, $t)>, )+
+        {
+            fn dispatch_with_indices<I: IntoIterator<Item = Self>>(iter: I) -> ( $( $c, )+ ) {
+                $( let mut $c_name = $c::default(); )+
+
+                for (index, element) in iter.into_iter().enumerate() {
+                    match element.into_either() {
+                        $( $either::$variant(value) => $c_name.extend(Some((index, value))), )+
+                    }
+                }
+
+                ( $( $c_name, )+ )
+            }
+        }
+    };
+}
+
+for_each_arity!(implement_dispatch_with_indices_for_arity);
+
+/// A dispatcher trait carrying context alongside values.
+///
+/// The iterator yields `(context, value)` pairs. Each value is dispatched in
+/// the container matching its variant, along with its context, so that the
+/// containers receive `(context, inner_value)` pairs.
+///
+/// ```
+/// use edisp_core::prelude::*;
+///
+/// let lines = vec![(1, Ok(42)), (2, Err("foo")), (3, Ok(101))];
+/// let (numbers, errors): (Vec<_>, Vec<_>) = Result::dispatch_with_context(lines);
+///
+/// assert_eq!(numbers, [(1, 42), (3, 101)]);
+/// assert_eq!(errors, [(2, "foo")]);
+/// ```
+pub trait DispatchWithContext<X, O>
+where
+    Self: Sized,
+{
+    /// Performs dispatching, keeping the context of each value.
+    fn dispatch_with_context<I: IntoIterator<Item = (X, Self)>>(iter: I) -> O;
+}
+
+/// Implements `DispatchWithContext` for every enum with a given number of
+/// variants.
+macro_rules! implement_dispatch_with_context_for_arity {
+    (
+        $either:ident,
+        $arity:literal,
+        $( (
+            $variant:ident,
+            $t:ident,
+            $c:ident,
+            $c_name:ident,
+            $f:ident,
+            $f_name:ident,
+            $idx:tt
+        ) ),+ $( , )?
+    ) => {
+        impl<E, X, $( $t, )+ $( $c, )+> DispatchWithContext<X, ( $( $c, )+ )> for E
+        where
+            E: Variants<Either = $either< $( $t, )+ >>,
+            $( $c: Default + Extend<(X, $t)>, )+
+        {
+            fn dispatch_with_context<I: IntoIterator<Item = (X, Self)>>(
+                iter: I,
+            ) -> ( $( $c, )+ ) {
+                $( let mut $c_name = $c::default(); )+
+
+                for (context, element) in iter {
+                    match element.into_either() {
+                        $( $either::$variant(value) => $c_name.extend(Some((context, value))), )+
+                    }
+                }
+
+                ( $( $c_name, )+ )
+            }
+        }
+    };
+}
+
+for_each_arity!(implement_dispatch_with_context_for_arity);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dispatch_with_indices_keeps_positions() {
+        let i = vec![Err("foo"), Ok(42), Ok(101), Err("bar")].into_iter();
+        let (some_oks, some_errs): (Vec<_>, Vec<_>) = Result::dispatch_with_indices(i);
+
+        assert_eq!(some_oks, [(1, 42), (2, 101)]);
+        assert_eq!(some_errs, [(0, "foo"), (3, "bar")]);
+    }
+
+    #[test]
+    fn dispatch_with_context_keeps_context() {
+        let i = vec![
+            ("a", Either3::V2(1)),
+            ("b", Either3::V3('!')),
+            ("c", Either3::V2(2)),
+        ];
+        let (ones, twos, threes): (Vec<(_, ())>, Vec<_>, Vec<_>) =
+            Either3::dispatch_with_context(i);
+
+        assert!(ones.is_empty());
+        assert_eq!(twos, [("a", 1), ("c", 2)]);
+        assert_eq!(threes, [("b", '!')]);
+    }
+}
@@ -0,0 +1,91 @@
+//! Identifying variants.
+//!
+//! Grouping values, counting them or reporting metrics requires to know which
+//! variant a value is, without moving it. This module contains the
+//! `VariantIndex` type, which identifies a variant, and the `VariantKind`
+//! trait, which gives the variant of a value along with the number and the
+//! names of the variants of its type.
+
+/// The zero-based index of a variant.
+///
+/// As enums have at most 8 variants, an index is stored in a single byte.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VariantIndex(u8);
+
+impl VariantIndex {
+    /// The maximum number of variants an enum can have.
+    pub const MAX: usize = 8;
+
+    /// Creates an index.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `index` is not lower than `VariantIndex::MAX`.
+    pub const fn new(index: usize) -> VariantIndex {
+        assert!(index < VariantIndex::MAX, "variant index out of range");
+        VariantIndex(index as u8)
+    }
+
+    /// Returns the index as a `usize`.
+    pub fn get(self) -> usize {
+        self.0.into()
+    }
+}
+
+impl From<VariantIndex> for usize {
+    fn from(index: VariantIndex) -> usize {
+        index.get()
+    }
+}
+
+/// An enum whose variants can be identified.
+///
+/// This trait is implemented by both the `implement_dispatch` macro and the
+/// `Dispatch` derive macro, as well as by the `EitherN` types, whose variants
+/// are named `V1`, `V2`, and so on.
+///
+/// ```
+/// use edisp_core::prelude::*;
+///
+/// let value: Result<u8, &str> = Err("foo");
+///
+/// assert_eq!(value.kind().get(), 1);
+/// assert_eq!(value.variant_name(), "Err");
+/// assert_eq!(Result::<u8, &str>::VARIANT_COUNT, 2);
+/// ```
+pub trait VariantKind {
+    /// The name of each variant, in declaration order.
+    const VARIANT_NAMES: &'static [&'static str];
+
+    /// The number of variants.
+    const VARIANT_COUNT: usize = Self::VARIANT_NAMES.len();
+
+    /// Returns the index of the variant of the value.
+    fn kind(&self) -> VariantIndex;
+
+    /// Returns the name of the variant of the value.
+    fn variant_name(&self) -> &'static str {
+        Self::VARIANT_NAMES[self.kind().get()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::either::Either4;
+
+    #[test]
+    fn either_variant_kind() {
+        let value = Either4::<(), (), u8, ()>::V3(42);
+
+        assert_eq!(value.kind(), VariantIndex::new(2));
+        assert_eq!(value.variant_name(), "V3");
+        assert_eq!(Either4::<(), (), u8, ()>::VARIANT_COUNT, 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "variant index out of range")]
+    fn variant_index_out_of_range() {
+        VariantIndex::new(8);
+    }
+}
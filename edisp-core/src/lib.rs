@@ -2,11 +2,57 @@
 //!
 //! See [`edisp` documentation] instead.
 //!
+//! The `std` feature is enabled by default. Without it, this crate is
+//! `no_std`, and only requires `alloc`: the dispatchers, the collections of
+//! `alloc` and the enums of `core` are still available, while `HashMap`,
+//! `HashSet`, threads, channels and I/O are not.
+//!
 //! [edisp]: https://crates.io/crates/edisp
 //! [`edisp` documentation]: https://docs.rs/edisp
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![forbid(missing_docs)]
 
+extern crate alloc;
+
+pub mod bounded;
+pub mod budget;
+pub mod by_kind;
+mod compat;
+pub mod containers;
+pub mod counts;
+#[cfg(feature = "serde")]
+pub mod de;
 pub mod dispatch;
+pub mod dispatcher;
+pub mod drain;
+pub mod either;
+pub mod expect;
+pub mod find;
+pub mod fold;
+pub mod indexed;
+pub mod kind;
+pub mod map;
+pub mod merge;
+pub mod nested;
+pub mod ordered;
+#[cfg(feature = "rayon")]
+pub mod parallel;
+pub mod partial;
+pub mod partition;
 pub mod prelude;
+pub mod progress;
+pub mod reserve;
+#[cfg(feature = "futures")]
+pub mod sink;
+pub mod slice;
+pub mod split;
 pub mod std_enums;
+#[cfg(feature = "futures")]
+pub mod stream;
+pub mod take;
+pub mod try_dispatch;
+pub mod until;
+pub mod windows;
+#[cfg(feature = "std")]
+pub mod workers;
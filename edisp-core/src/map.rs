@@ -0,0 +1,214 @@
+//! Classifying and dispatching in a single pass.
+//!
+//! Values to dispatch are often not enums yet: log lines, bytes or rows must
+//! first be classified. This module contains the `DispatchMap` trait, which
+//! calls a classifier on each value and dispatches its result in the same
+//! loop, possibly discarding irrelevant values or keeping track of a state.
+
+use crate::{dispatch::Variants, either::*};
+
+/// A dispatcher trait which classifies values before dispatching them.
+///
+/// This trait behaves like `Dispatch`, except that the iterator may yield
+/// any type. The closure `f` is called on each value, and the variant it
+/// returns decides in which container the value ends up.
+///
+/// ```
+/// use edisp_core::prelude::*;
+///
+/// let lines = vec!["42", "foo", "101"];
+/// let (numbers, errors): (Vec<_>, Vec<_>) = Result::dispatch_map(lines, str::parse::<u8>);
+///
+/// assert_eq!(numbers, [42, 101]);
+/// assert_eq!(errors.len(), 1);
+/// ```
+pub trait DispatchMap<O>
+where
+    Self: Sized,
+{
+    /// Performs classification and dispatching.
+    fn dispatch_map<I, F>(iter: I, f: F) -> O
+    where
+        I: IntoIterator,
+        F: FnMut(I::Item) -> Self;
+
+    /// Performs classification and dispatching, discarding some values.
+    ///
+    /// Values for which `f` returns `None` are dropped:
+    ///
+    /// ```
+    /// use edisp_core::prelude::*;
+    ///
+    /// let lines = vec!["# comment", "42", "", "foo"];
+    /// let (numbers, errors): (Vec<_>, Vec<_>) = Result::dispatch_filter_map(lines, |line| {
+    ///     if line.is_empty() || line.starts_with('#') {
+    ///         None
+    ///     } else {
+    ///         Some(line.parse::<u8>())
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(numbers, [42]);
+    /// assert_eq!(errors.len(), 1);
+    /// ```
+    fn dispatch_filter_map<I, F>(iter: I, f: F) -> O
+    where
+        I: IntoIterator,
+        F: FnMut(I::Item) -> Option<Self>;
+
+    /// Performs classification and dispatching, with a mutable state.
+    ///
+    /// The closure `f` receives the state along with each value, so that the
+    /// classification can depend on the values which were met before. The
+    /// final state is returned along with the containers:
+    ///
+    /// ```
+    /// use edisp_core::prelude::*;
+    ///
+    /// let statuses = vec![200, 404, 500, 404, 200];
+    ///
+    /// // Client errors become fatal once a server error has been met.
+    /// let ((warnings, errors), failed): ((Vec<_>, Vec<_>), _) =
+    ///     Result::dispatch_scan(statuses, false, |failed, status| {
+    ///         *failed |= status >= 500;
+    ///         if status >= 500 || (*failed && status >= 400) {
+    ///             Err(status)
+    ///         } else {
+    ///             Ok(status)
+    ///         }
+    ///     });
+    ///
+    /// assert_eq!(warnings, [200, 404, 200]);
+    /// assert_eq!(errors, [500, 404]);
+    /// assert!(failed);
+    /// ```
+    fn dispatch_scan<I, S, F>(iter: I, mut state: S, mut f: F) -> (O, S)
+    where
+        I: IntoIterator,
+        F: FnMut(&mut S, I::Item) -> Self,
+    {
+        let containers = Self::dispatch_map(iter, |item| f(&mut state, item));
+        (containers, state)
+    }
+}
+
+/// Implements `DispatchMap` for every enum with a given number of variants.
+macro_rules! implement_dispatch_map_for_arity {
+    (
+        $either:ident,
+        $arity:literal,
+        $( (
+            $variant:ident,
+            $t:ident,
+            $c:ident,
+            $c_name:ident,
+            $f:ident,
+            $f_name:ident,
+            $idx:tt
+        ) ),+ $( , )?
+    ) => {
+        impl<E, $( $t, )+ $( $c, )+> DispatchMap<( $( $c, )+ )> for E
+        where
+            E: Variants<Either = $either< $( $t, )+ >>,
+            $( $c: Default + Extend<$t>, )+
+        {
+            fn dispatch_map<I, F>(iter: I, mut f: F) -> ( $( $c, )+ )
+            where
+                I: IntoIterator,
+                F: FnMut(I::Item) -> Self,
+            {
+                $( let mut $c_name = $c::default(); )+
+
+                for item in iter {
+                    match f(item).into_either() {
+                        $( $either::$variant(value) => $c_name.extend(Some(value)), )+
+                    }
+                }
+
+                ( $( $c_name, )+ )
+            }
+
+            fn dispatch_filter_map<I, F>(iter: I, mut f: F) -> ( $( $c, )+ )
+            where
+                I: IntoIterator,
+                F: FnMut(I::Item) -> Option<Self>,
+            {
+                $( let mut $c_name = $c::default(); )+
+
+                for item in iter {
+                    match f(item).map(Variants::into_either) {
+                        $( Some($either::$variant(value)) => $c_name.extend(Some(value)), )+
+                        None => {}
+                    }
+                }
+
+                ( $( $c_name, )+ )
+            }
+        }
+    };
+}
+
+for_each_arity!(implement_dispatch_map_for_arity);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dispatch::DispatchIterator;
+
+    #[test]
+    fn dispatch_map_classifies_values() {
+        let i = vec![3, 10, 7, 4];
+        let (evens, odds): (Vec<_>, Vec<_>) = Either2::dispatch_map(i, |n| {
+            if n % 2 == 0 {
+                Either2::V1(n / 2)
+            } else {
+                Either2::V2(n)
+            }
+        });
+
+        assert_eq!(evens, [5, 2]);
+        assert_eq!(odds, [3, 7]);
+    }
+
+    #[test]
+    fn dispatch_map_method() {
+        let (chars, digits): (String, Vec<_>) =
+            "a1b2".chars().dispatch_map(|c| match c.to_digit(10) {
+                Some(digit) => Err(digit),
+                None => Ok(c),
+            });
+
+        assert_eq!(chars, "ab");
+        assert_eq!(digits, [1, 2]);
+    }
+
+    #[test]
+    fn dispatch_scan_counts_values() {
+        let ((small, big), seen): ((Vec<_>, Vec<_>), _) =
+            Either2::dispatch_scan(vec![7, 1, 3], 0, |seen, n| {
+                *seen += 1;
+                if n < 5 {
+                    Either2::V1((*seen, n))
+                } else {
+                    Either2::V2(n)
+                }
+            });
+
+        assert_eq!(small, [(2, 1), (3, 3)]);
+        assert_eq!(big, [7]);
+        assert_eq!(seen, 3);
+    }
+
+    #[test]
+    fn dispatch_filter_map_discards_none() {
+        let (small, big): (Vec<_>, Vec<_>) = (0..10).dispatch_filter_map(|n| match n {
+            0 => None,
+            1..=3 => Some(Ok(n)),
+            _ if n % 2 == 0 => Some(Err(n)),
+            _ => None,
+        });
+
+        assert_eq!(small, [1, 2, 3]);
+        assert_eq!(big, [4, 6, 8]);
+    }
+}
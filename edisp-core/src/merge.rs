@@ -0,0 +1,310 @@
+//! Merging partial dispatch results.
+//!
+//! Dispatching can be split: each part of the input is dispatched in its own
+//! containers, which are then combined. This module contains the `Merge`
+//! trait, implemented by containers which can be combined, and the
+//! `DispatchChunked` trait, which dispatches an iterator chunk by chunk,
+//! possibly on several threads, and merges the results.
+
+use alloc::collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque};
+use alloc::string::String;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::hash::{BuildHasher, Hash};
+#[cfg(feature = "std")]
+use core::num::NonZeroUsize;
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+#[cfg(feature = "std")]
+use std::sync::{Mutex, PoisonError};
+#[cfg(feature = "std")]
+use std::thread;
+
+use crate::{dispatch::Dispatch, dispatcher::Parts, either::for_each_arity};
+
+/// A container which can absorb another one.
+///
+/// Merging two containers must give the same result as extending the first
+/// one with the values which were added to the second one. For maps, this
+/// means that the entries of `other` replace the ones of `self`.
+///
+/// This trait is implemented for tuples of containers, so that the output of
+/// a dispatch can be merged with another one.
+///
+/// ```
+/// use edisp_core::prelude::*;
+///
+/// let mut left = (vec![1], String::from("foo"));
+/// left.merge((vec![2, 3], String::from("bar")));
+///
+/// assert_eq!(left, (vec![1, 2, 3], String::from("foobar")));
+/// ```
+pub trait Merge {
+    /// Adds the content of `other` to `self`.
+    fn merge(&mut self, other: Self);
+}
+
+/// Implements `Merge` for a collection which has an `append` method.
+macro_rules! implement_append_merge {
+    ( $( [ $( $generics:tt )* ] $collection:ty ),+ $( , )? ) => {
+        $(
+            impl< $( $generics )* > Merge for $collection {
+                fn merge(&mut self, mut other: Self) {
+                    self.append(&mut other);
+                }
+            }
+        )+
+    };
+}
+
+implement_append_merge! {
+    [T] Vec<T>,
+    [T] VecDeque<T>,
+    [T] LinkedList<T>,
+    [T: Ord] BinaryHeap<T>,
+    [T: Ord] BTreeSet<T>,
+    [K: Ord, V] BTreeMap<K, V>,
+}
+
+/// Implements `Merge` for a collection which can be extended with its own
+/// values.
+#[cfg(feature = "std")]
+macro_rules! implement_extend_merge {
+    ( $( [ $( $generics:tt )* ] $collection:ty ),+ $( , )? ) => {
+        $(
+            impl< $( $generics )* > Merge for $collection {
+                fn merge(&mut self, other: Self) {
+                    self.extend(other);
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "std")]
+implement_extend_merge! {
+    [T: Eq + Hash, S: BuildHasher] HashSet<T, S>,
+    [K: Eq + Hash, V, S: BuildHasher] HashMap<K, V, S>,
+}
+
+impl Merge for String {
+    fn merge(&mut self, other: String) {
+        self.push_str(&other);
+    }
+}
+
+impl<O: Merge> Merge for Parts<O> {
+    fn merge(&mut self, other: Self) {
+        self.0.merge(other.0);
+    }
+}
+
+/// Implements `Merge` for every tuple of containers with a given arity.
+macro_rules! implement_merge_for_arity {
+    (
+        $either:ident,
+        $arity:literal,
+        $( (
+            $variant:ident,
+            $t:ident,
+            $c:ident,
+            $c_name:ident,
+            $f:ident,
+            $f_name:ident,
+            $idx:tt
+        ) ),+ $( , )?
+    ) => {
+        impl<$( $c, )+> Merge for ( $( $c, )+ )
+        where
+            $( $c: Merge, )+
+        {
+            fn merge(&mut self, other: Self) {
+                $( self.$idx.merge(other.$idx); )+
+            }
+        }
+    };
+}
+
+for_each_arity!(implement_merge_for_arity);
+
+/// A dispatcher trait working chunk by chunk.
+///
+/// Values are grouped in chunks of `chunk_size` values. Each chunk is
+/// dispatched in its own containers, which are then merged, in order, with
+/// `Merge`. The result is the same as the one of `Dispatch`.
+///
+/// # Panics
+///
+/// Both methods panic if `chunk_size` is zero.
+///
+/// ```
+/// use edisp_core::prelude::*;
+///
+/// let iter = (1..=10).map(|n| if n % 3 == 0 { Err(n) } else { Ok(n) });
+/// let (some_oks, some_errs): (Vec<_>, Vec<_>) = Result::dispatch_chunked(iter, 4);
+///
+/// assert_eq!(some_oks, [1, 2, 4, 5, 7, 8, 10]);
+/// assert_eq!(some_errs, [3, 6, 9]);
+/// ```
+pub trait DispatchChunked<O>
+where
+    Self: Sized,
+{
+    /// Performs dispatching chunk by chunk, on the current thread.
+    fn dispatch_chunked<I: IntoIterator<Item = Self>>(iter: I, chunk_size: usize) -> O;
+
+    /// Performs dispatching chunk by chunk, on several scoped threads.
+    ///
+    /// The whole input is read before any thread is spawned. At most
+    /// `std::thread::available_parallelism` threads are spawned, each of them
+    /// dispatching chunks until none is left. The partial results are then
+    /// merged in the order of the chunks. This method is only available with
+    /// the `std` feature.
+    #[cfg(feature = "std")]
+    fn dispatch_chunked_scoped<I: IntoIterator<Item = Self>>(iter: I, chunk_size: usize) -> O
+    where
+        Self: Send,
+        O: Send;
+}
+
+impl<E, O> DispatchChunked<O> for E
+where
+    E: Dispatch<O>,
+    O: Default + Merge,
+{
+    fn dispatch_chunked<I: IntoIterator<Item = Self>>(iter: I, chunk_size: usize) -> O {
+        let mut output = O::default();
+
+        for chunk in chunks(iter, chunk_size) {
+            output.merge(E::dispatch(chunk));
+        }
+
+        output
+    }
+
+    #[cfg(feature = "std")]
+    fn dispatch_chunked_scoped<I: IntoIterator<Item = Self>>(iter: I, chunk_size: usize) -> O
+    where
+        Self: Send,
+        O: Send,
+    {
+        let chunks = chunks(iter, chunk_size).collect::<Vec<_>>();
+        let workers = thread::available_parallelism()
+            .map(NonZeroUsize::get)
+            .unwrap_or(1)
+            .min(chunks.len());
+        let queue = Mutex::new(chunks.into_iter().enumerate());
+
+        let mut partials = thread::scope(|scope| {
+            let handles = (0..workers)
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut partials = Vec::new();
+                        // The lock is released before the chunk is
+                        // dispatched, so that workers run concurrently.
+                        while let Some((idx, chunk)) = next_chunk(&queue) {
+                            partials.push((idx, E::dispatch(chunk)));
+                        }
+                        partials
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            let mut partials = Vec::new();
+            for handle in handles {
+                match handle.join() {
+                    Ok(worker_partials) => partials.extend(worker_partials),
+                    Err(payload) => std::panic::resume_unwind(payload),
+                }
+            }
+
+            partials
+        });
+
+        partials.sort_unstable_by_key(|(idx, _)| *idx);
+
+        let mut output = O::default();
+        for (_, partial) in partials {
+            output.merge(partial);
+        }
+
+        output
+    }
+}
+
+/// Takes the next chunk to dispatch from a queue shared between threads.
+#[cfg(feature = "std")]
+fn next_chunk<I: Iterator>(queue: &Mutex<I>) -> Option<I::Item> {
+    queue.lock().unwrap_or_else(PoisonError::into_inner).next()
+}
+
+/// Groups the values yielded by an iterator in chunks of a given size.
+fn chunks<I: IntoIterator>(iter: I, chunk_size: usize) -> impl Iterator<Item = Vec<I::Item>> {
+    assert!(chunk_size != 0, "chunk size must be non-zero");
+
+    let mut iter = iter.into_iter();
+    core::iter::from_fn(move || {
+        let chunk = iter.by_ref().take(chunk_size).collect::<Vec<_>>();
+        if chunk.is_empty() {
+            None
+        } else {
+            Some(chunk)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::containers::{Count, First, Last, MaxOf};
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn merge_maps_and_sets() {
+        let mut map = HashMap::new();
+        map.insert(1, "one");
+        map.merge(vec![(1, "uno"), (2, "dos")].into_iter().collect());
+
+        let mut set = BTreeSet::new();
+        set.insert(2);
+        set.merge(vec![1, 2, 3].into_iter().collect());
+
+        assert_eq!(map[&1], "uno");
+        assert_eq!(map.len(), 2);
+        assert_eq!(set.into_iter().collect::<Vec<_>>(), [1, 2, 3]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn dispatch_chunked_matches_dispatch() {
+        let i = (0..25).map(|n| if n % 4 == 0 { Err(n) } else { Ok(n) });
+
+        let expected: (Vec<_>, Vec<_>) = Result::dispatch(i.clone());
+        let chunked: (Vec<_>, Vec<_>) = Result::dispatch_chunked(i.clone(), 3);
+        let scoped: (Vec<_>, Vec<_>) = Result::dispatch_chunked_scoped(i.clone(), 7);
+        let many_chunks: (Vec<_>, Vec<_>) = Result::dispatch_chunked_scoped(i, 1);
+
+        assert_eq!(chunked, expected);
+        assert_eq!(scoped, expected);
+        assert_eq!(many_chunks, expected);
+    }
+
+    #[test]
+    fn dispatch_chunked_reducers() {
+        let i = vec![Ok(4), Err('a'), Ok(9), Ok(2), Err('b'), Err('c')];
+
+        let (max, last): (MaxOf<_>, Last<_>) = Result::dispatch_chunked(i.clone(), 2);
+        assert_eq!(max.into_inner(), Some(9));
+        assert_eq!(last.into_inner(), Some('c'));
+
+        let (count, first): (Count, First<_>) = Result::dispatch_chunked(i, 2);
+        assert_eq!(count.into_inner(), 3);
+        assert_eq!(first.into_inner(), Some('a'));
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn dispatch_chunked_zero_size() {
+        let _: (Vec<u8>, Vec<u8>) = Result::dispatch_chunked(vec![Ok::<u8, u8>(1)], 0);
+    }
+}
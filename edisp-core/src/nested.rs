@@ -0,0 +1,180 @@
+//! Dispatching nested enums.
+//!
+//! IO and parsing pipelines often produce nested enums, such as
+//! `Result<Result<T, E1>, E2>`, which have to be flattened before being
+//! dispatched. This module contains iterator adapters which dispatch such
+//! values in three containers, in a single pass.
+
+use crate::{dispatch::Dispatch, either::Either3};
+
+/// Allows to dispatch `Result<Result<T, E1>, E2>` values.
+pub trait CollectNestedResult<T, E1, E2> {
+    /// Collects values and dispatch them.
+    ///
+    /// The first container receives the inner `Ok` values, the second one
+    /// the inner `Err` values, and the third one the outer `Err` values.
+    ///
+    /// ```
+    /// use edisp_core::prelude::*;
+    ///
+    /// let lines = vec![Ok("42"), Err("unreadable"), Ok("foo"), Ok("101")];
+    /// let (numbers, parse_errors, io_errors): (Vec<_>, Vec<_>, Vec<_>) = lines
+    ///     .into_iter()
+    ///     .map(|line| line.map(str::parse::<u8>))
+    ///     .dispatch_nested_result();
+    ///
+    /// assert_eq!(numbers, [42, 101]);
+    /// assert_eq!(parse_errors.len(), 1);
+    /// assert_eq!(io_errors, ["unreadable"]);
+    /// ```
+    fn dispatch_nested_result<C, D, F>(self) -> (C, D, F)
+    where
+        C: Default + Extend<T>,
+        D: Default + Extend<E1>,
+        F: Default + Extend<E2>;
+}
+
+impl<T, E1, E2, I> CollectNestedResult<T, E1, E2> for I
+where
+    I: Iterator<Item = Result<Result<T, E1>, E2>>,
+{
+    fn dispatch_nested_result<C, D, F>(self) -> (C, D, F)
+    where
+        C: Default + Extend<T>,
+        D: Default + Extend<E1>,
+        F: Default + Extend<E2>,
+    {
+        Either3::dispatch(self.map(|value| match value {
+            Ok(Ok(value)) => Either3::V1(value),
+            Ok(Err(error)) => Either3::V2(error),
+            Err(error) => Either3::V3(error),
+        }))
+    }
+}
+
+/// Allows to dispatch `Result<Option<T>, E>` values.
+pub trait CollectResultOption<T, E> {
+    /// Collects values and dispatch them.
+    ///
+    /// The first container receives the values wrapped in `Ok(Some(_))`, the
+    /// second one receives a `()` for each `Ok(None)`, and the third one the
+    /// `Err` values.
+    ///
+    /// ```
+    /// use edisp_core::prelude::*;
+    ///
+    /// let lookups = vec![Ok(Some(1)), Ok(None), Err("timeout"), Ok(Some(2))];
+    /// let (found, missing, errors): (Vec<_>, Vec<_>, Vec<_>) =
+    ///     lookups.into_iter().dispatch_result_option();
+    ///
+    /// assert_eq!(found, [1, 2]);
+    /// assert_eq!(missing.len(), 1);
+    /// assert_eq!(errors, ["timeout"]);
+    /// ```
+    fn dispatch_result_option<C, D, F>(self) -> (C, D, F)
+    where
+        C: Default + Extend<T>,
+        D: Default + Extend<()>,
+        F: Default + Extend<E>;
+}
+
+impl<T, E, I> CollectResultOption<T, E> for I
+where
+    I: Iterator<Item = Result<Option<T>, E>>,
+{
+    fn dispatch_result_option<C, D, F>(self) -> (C, D, F)
+    where
+        C: Default + Extend<T>,
+        D: Default + Extend<()>,
+        F: Default + Extend<E>,
+    {
+        Either3::dispatch(self.map(|value| match value {
+            Ok(Some(value)) => Either3::V1(value),
+            Ok(None) => Either3::V2(()),
+            Err(error) => Either3::V3(error),
+        }))
+    }
+}
+
+/// Allows to dispatch `Option<Result<T, E>>` values.
+pub trait CollectOptionResult<T, E> {
+    /// Collects values and dispatch them.
+    ///
+    /// The first container receives the values wrapped in `Some(Ok(_))`, the
+    /// second one the values wrapped in `Some(Err(_))`, and the third one
+    /// receives a `()` for each `None`.
+    ///
+    /// ```
+    /// use edisp_core::prelude::*;
+    ///
+    /// let fields = vec![Some("42"), None, Some("foo"), Some("101")];
+    /// let (numbers, parse_errors, missing): (Vec<_>, Vec<_>, Vec<_>) = fields
+    ///     .into_iter()
+    ///     .map(|field| field.map(str::parse::<u8>))
+    ///     .dispatch_option_result();
+    ///
+    /// assert_eq!(numbers, [42, 101]);
+    /// assert_eq!(parse_errors.len(), 1);
+    /// assert_eq!(missing.len(), 1);
+    /// ```
+    fn dispatch_option_result<C, D, F>(self) -> (C, D, F)
+    where
+        C: Default + Extend<T>,
+        D: Default + Extend<E>,
+        F: Default + Extend<()>;
+}
+
+impl<T, E, I> CollectOptionResult<T, E> for I
+where
+    I: Iterator<Item = Option<Result<T, E>>>,
+{
+    fn dispatch_option_result<C, D, F>(self) -> (C, D, F)
+    where
+        C: Default + Extend<T>,
+        D: Default + Extend<E>,
+        F: Default + Extend<()>,
+    {
+        Either3::dispatch(self.map(|value| match value {
+            Some(Ok(value)) => Either3::V1(value),
+            Some(Err(error)) => Either3::V2(error),
+            None => Either3::V3(()),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::containers::Count;
+
+    #[test]
+    fn dispatch_nested_result_impl() {
+        let i = vec![Ok(Ok(1)), Err('!'), Ok(Err("foo")), Ok(Ok(2))].into_iter();
+        let (some_oks, some_errs, some_chars): (Vec<_>, Vec<_>, String) =
+            i.dispatch_nested_result();
+
+        assert_eq!(some_oks, [1, 2]);
+        assert_eq!(some_errs, ["foo"]);
+        assert_eq!(some_chars, "!");
+    }
+
+    #[test]
+    fn dispatch_result_option_impl() {
+        let i = vec![Ok(None), Ok(Some(42)), Ok(None), Err("foo")].into_iter();
+        let (some_values, nones, some_errs): (Vec<_>, Count, Vec<_>) = i.dispatch_result_option();
+
+        assert_eq!(some_values, [42]);
+        assert_eq!(nones.into_inner(), 2);
+        assert_eq!(some_errs, ["foo"]);
+    }
+
+    #[test]
+    fn dispatch_option_result_impl() {
+        let i = vec![None, Some(Ok(42)), Some(Err('!')), None].into_iter();
+        let (some_values, some_errs, nones): (Vec<_>, String, Count) = i.dispatch_option_result();
+
+        assert_eq!(some_values, [42]);
+        assert_eq!(some_errs, "!");
+        assert_eq!(nones.into_inner(), 2);
+    }
+}
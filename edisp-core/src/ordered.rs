@@ -0,0 +1,220 @@
+//! Dispatching while recording the order of variants.
+//!
+//! Dispatching loses the relative order of values which end up in different
+//! containers. Unlike `DispatchWithIndices`, which stores a position next to
+//! each value, this module contains the `DispatchOrdered` trait, which
+//! records the sequence of variants aside, one byte per value, and the
+//! `Undispatch` trait, which uses this sequence to rebuild the original
+//! values.
+
+use alloc::vec::{self, Vec};
+use core::marker::PhantomData;
+
+use crate::{dispatch::Variants, either::*, kind::VariantIndex};
+
+/// A dispatcher trait recording the order of variants.
+///
+/// This trait behaves like `Dispatch`, but also returns the index of the
+/// variant of each dispatched value, in the iteration order. This allows to
+/// interleave the containers back later, or to render a diff.
+///
+/// ```
+/// use edisp_core::prelude::*;
+///
+/// let iter = vec![Ok(1), Err("foo"), Ok(2)].into_iter();
+/// let ((some_oks, some_errs), order): ((Vec<_>, Vec<_>), _) = Result::dispatch_ordered(iter);
+///
+/// assert_eq!(some_oks, [1, 2]);
+/// assert_eq!(some_errs, ["foo"]);
+/// assert_eq!(order.iter().map(|index| index.get()).collect::<Vec<_>>(), [0, 1, 0]);
+/// ```
+pub trait DispatchOrdered<O>
+where
+    Self: Sized,
+{
+    /// Performs dispatching, recording the order of variants.
+    fn dispatch_ordered<I: IntoIterator<Item = Self>>(iter: I) -> (O, Vec<VariantIndex>);
+}
+
+/// Implements `DispatchOrdered` for every enum with a given number of
+/// variants.
+macro_rules! implement_dispatch_ordered_for_arity {
+    (
+        $either:ident,
+        $arity:literal,
+        $( (
+            $variant:ident,
+            $t:ident,
+            $c:ident,
+            $c_name:ident,
+            $f:ident,
+            $f_name:ident,
+            $idx:tt
+        ) ),+ $( , )?
+    ) => {
+        impl<E, $( $t, )+ $( $c, )+> DispatchOrdered<( $( $c, )+ )> for E
+        where
+            E: Variants<Either = $either< $( $t, )+ >>,
+            $( $c: Default + Extend<$t>, )+
+        {
+            fn dispatch_ordered<I: IntoIterator<Item = Self>>(
+                iter: I,
+            ) -> (( $( $c, )+ ), Vec<VariantIndex>) {
+                let iter = iter.into_iter();
+                let mut order = Vec::with_capacity(iter.size_hint().0);
+                $( let mut $c_name = $c::default(); )+
+
+                for element in iter {
+                    match element.into_either() {
+                        $(
+                            $either::$variant(value) => {
+                                order.push(VariantIndex::new($idx));
+                                $c_name.extend(Some(value));
+                            }
+                        )+
+                    }
+                }
+
+                (( $( $c_name, )+ ), order)
+            }
+        }
+    };
+}
+
+for_each_arity!(implement_dispatch_ordered_for_arity);
+
+/// An iterator rebuilding values from their containers.
+///
+/// It is returned by `Undispatch::undispatch`. `E` is the type of the
+/// rebuilt values, and `I` is a tuple containing an iterator over each
+/// container.
+#[derive(Debug)]
+pub struct Undispatched<E, I> {
+    iters: I,
+    order: vec::IntoIter<VariantIndex>,
+    marker: PhantomData<fn() -> E>,
+}
+
+/// A trait rebuilding values from their containers.
+///
+/// This is the reverse operation of `DispatchOrdered`: given the containers
+/// and the sequence of variants, each value is taken from its container and
+/// wrapped back in its variant, in the original order. Containers may have
+/// been transformed in between, as long as each of them still holds as many
+/// values.
+///
+/// The returned iterator stops when the sequence of variants is exhausted,
+/// or when it refers to a container which has no value left.
+///
+/// ```
+/// use edisp_core::prelude::*;
+///
+/// let iter = vec![Ok(1), Err("foo"), Ok(2)].into_iter();
+/// let ((some_oks, some_errs), order): ((Vec<_>, Vec<_>), _) = Result::dispatch_ordered(iter);
+///
+/// let doubled = some_oks.into_iter().map(|n| n * 2).collect::<Vec<_>>();
+/// let rebuilt = Result::undispatch((doubled, some_errs), order).collect::<Vec<_>>();
+///
+/// assert_eq!(rebuilt, [Ok(2), Err("foo"), Ok(4)]);
+/// ```
+pub trait Undispatch<O>
+where
+    Self: Sized,
+{
+    /// A tuple containing an iterator over each container.
+    type Iters;
+
+    /// Rebuilds values from their containers.
+    fn undispatch(containers: O, order: Vec<VariantIndex>) -> Undispatched<Self, Self::Iters>;
+}
+
+/// Implements `Undispatch` for every enum with a given number of variants.
+macro_rules! implement_undispatch_for_arity {
+    (
+        $either:ident,
+        $arity:literal,
+        $( (
+            $variant:ident,
+            $t:ident,
+            $c:ident,
+            $c_name:ident,
+            $f:ident,
+            $f_name:ident,
+            $idx:tt
+        ) ),+ $( , )?
+    ) => {
+        impl<E, $( $t, )+ $( $c, )+> Undispatch<( $( $c, )+ )> for E
+        where
+            E: Variants<Either = $either< $( $t, )+ >>,
+            $( $c: IntoIterator<Item = $t>, )+
+        {
+            type Iters = ( $( $c::IntoIter, )+ );
+
+            fn undispatch(
+                containers: ( $( $c, )+ ),
+                order: Vec<VariantIndex>,
+            ) -> Undispatched<Self, Self::Iters> {
+                Undispatched {
+                    iters: ( $( containers.$idx.into_iter(), )+ ),
+                    order: order.into_iter(),
+                    marker: PhantomData,
+                }
+            }
+        }
+
+        impl<E, $( $t, )+ $( $c, )+> Iterator for Undispatched<E, ( $( $c, )+ )>
+        where
+            E: Variants<Either = $either< $( $t, )+ >>,
+            $( $c: Iterator<Item = $t>, )+
+        {
+            type Item = E;
+
+            fn next(&mut self) -> Option<E> {
+                let either = match self.order.next()?.get() {
+                    $( $idx => $either::$variant(self.iters.$idx.next()?), )+
+                    _ => return None,
+                };
+
+                Some(E::from_either(either))
+            }
+        }
+    };
+}
+
+for_each_arity!(implement_undispatch_for_arity);
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use super::*;
+
+    #[test]
+    fn undispatch_rebuilds_original_order() {
+        let i = vec![
+            Either3::V3('a'),
+            Either3::V1(1),
+            Either3::V2("foo"),
+            Either3::V1(2),
+        ];
+        let (containers, order): ((Vec<_>, Vec<_>, VecDeque<_>), _) =
+            Either3::dispatch_ordered(i.clone());
+
+        assert_eq!(
+            Either3::undispatch(containers, order).collect::<Vec<_>>(),
+            i
+        );
+    }
+
+    #[test]
+    fn undispatch_stops_on_missing_value() {
+        let order = vec![
+            VariantIndex::new(1),
+            VariantIndex::new(0),
+            VariantIndex::new(1),
+        ];
+        let rebuilt = Result::undispatch((vec![1, 2], vec!["foo"]), order).collect::<Vec<_>>();
+
+        assert_eq!(rebuilt, [Err("foo"), Ok(1)]);
+    }
+}
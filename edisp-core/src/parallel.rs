@@ -0,0 +1,85 @@
+//! Parallel dispatching with `rayon`.
+//!
+//! `DispatchChunked` splits its input in chunks of a fixed size, and spawns a
+//! thread for each of them. This module contains the `ParallelDispatch`
+//! trait, which dispatches a rayon parallel iterator instead: values are
+//! dispatched in partial containers by each worker of the thread pool, which
+//! are then combined with `Merge`.
+//!
+//! This module is only available with the `rayon` feature.
+
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+use crate::{dispatcher::DispatchInto, merge::Merge};
+
+/// A dispatcher trait working on parallel iterators.
+///
+/// The generic type `O` should be a tuple whose arity is equal to the number
+/// of variants of the implementor, and should contain only types which
+/// implement `Default`, `Extend` and `Merge`.
+///
+/// When the parallel iterator is indexed, such as the one of a `Vec` or of a
+/// range, partial containers are merged in order, and the result is the same
+/// as the one of `Dispatch`.
+///
+/// ```
+/// use edisp_core::prelude::*;
+/// use rayon::prelude::*;
+///
+/// let iter = (1..=10).into_par_iter().map(|n| if n % 3 == 0 { Err(n) } else { Ok(n) });
+/// let (some_oks, some_errs): (Vec<_>, Vec<_>) = Result::par_dispatch(iter);
+///
+/// assert_eq!(some_oks, [1, 2, 4, 5, 7, 8, 10]);
+/// assert_eq!(some_errs, [3, 6, 9]);
+/// ```
+pub trait ParallelDispatch<O>
+where
+    Self: Sized + Send,
+{
+    /// Performs dispatching on the rayon thread pool.
+    fn par_dispatch<I: IntoParallelIterator<Item = Self>>(iter: I) -> O;
+}
+
+impl<E, O> ParallelDispatch<O> for E
+where
+    E: DispatchInto<O> + Send,
+    O: Default + Merge + Send,
+{
+    fn par_dispatch<I: IntoParallelIterator<Item = Self>>(iter: I) -> O {
+        iter.into_par_iter()
+            .fold(O::default, |mut containers, value| {
+                value.dispatch_into(&mut containers);
+                containers
+            })
+            .reduce(O::default, |mut left, right| {
+                left.merge(right);
+                left
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rayon::prelude::*;
+
+    use super::*;
+    use crate::{containers::Count, prelude::*};
+
+    #[test]
+    fn par_dispatch_unindexed() {
+        let i = (0..1000).into_par_iter().filter(|n| n % 2 == 0).map(|n| {
+            if n % 3 == 0 {
+                Either3::V1(n)
+            } else if n % 3 == 1 {
+                Either3::V2(())
+            } else {
+                Either3::V3(n)
+            }
+        });
+        let (mut threes, others, _): (Vec<_>, Count, Vec<_>) = Either3::par_dispatch(i);
+        threes.sort_unstable();
+
+        assert_eq!(threes, (0..1000).step_by(6).collect::<Vec<_>>());
+        assert_eq!(others.into_inner(), 166);
+    }
+}
@@ -0,0 +1,110 @@
+//! Dispatching some variants only.
+//!
+//! Multi-stage pipelines often handle some variants at a given stage, and
+//! forward the other ones to the next stage. This module contains the
+//! `DispatchPartial` trait, which dispatches values of the selected variants
+//! and keeps the other values intact.
+
+use crate::{dispatch::Variants, either::*};
+
+/// A dispatcher trait which dispatches some variants only.
+///
+/// The generic type `O` should be a tuple whose arity is equal to the number
+/// of variants of the implementor, and should contain only types which
+/// implement `Default` and `Extend`. Values of the variants selected by
+/// `selected` are dispatched in their container, while the other values are
+/// added, untouched, to the remainder container. Containers of the variants
+/// which are not selected stay empty, so `Ignore` is a good fit for them.
+///
+/// ```
+/// use edisp_core::{containers::Ignore, prelude::*};
+///
+/// let iter = vec![Ok(1), Err("foo"), Ok(2)].into_iter();
+/// let ((some_oks, Ignore), rest): ((Vec<_>, _), Vec<_>) =
+///     Result::dispatch_partial(iter, [true, false]);
+///
+/// assert_eq!(some_oks, [1, 2]);
+/// assert_eq!(rest, [Err("foo")]);
+/// ```
+pub trait DispatchPartial<O>
+where
+    Self: Variants,
+    Self::Either: EitherN,
+{
+    /// Dispatches the values of the selected variants, and collects the
+    /// other values in a remainder container.
+    fn dispatch_partial<I, R>(iter: I, selected: <Self::Either as EitherN>::Flags) -> (O, R)
+    where
+        I: IntoIterator<Item = Self>,
+        R: Default + Extend<Self>;
+}
+
+/// Implements `DispatchPartial` for every enum with a given number of
+/// variants.
+macro_rules! implement_dispatch_partial_for_arity {
+    (
+        $either:ident,
+        $arity:literal,
+        $( (
+            $variant:ident,
+            $t:ident,
+            $c:ident,
+            $c_name:ident,
+            $f:ident,
+            $f_name:ident,
+            $idx:tt
+        ) ),+ $( , )?
+    ) => {
+        impl<E, $( $t, )+ $( $c, )+> DispatchPartial<( $( $c, )+ )> for E
+        where
+            E: Variants<Either = $either< $( $t, )+ >>,
+            $( $c: Default + Extend<$t>, )+
+        {
+            fn dispatch_partial<I, R>(iter: I, selected: [bool; $arity]) -> (( $( $c, )+ ), R)
+            where
+                I: IntoIterator<Item = Self>,
+                R: Default + Extend<Self>,
+            {
+                $( let mut $c_name = $c::default(); )+
+                let mut rest = R::default();
+
+                for element in iter {
+                    match element.into_either() {
+                        $(
+                            $either::$variant(value) if selected[$idx] => {
+                                $c_name.extend(Some(value));
+                            }
+                        )+
+                        either => rest.extend(Some(E::from_either(either))),
+                    }
+                }
+
+                (( $( $c_name, )+ ), rest)
+            }
+        }
+    };
+}
+
+for_each_arity!(implement_dispatch_partial_for_arity);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dispatch_partial_forwards_remainder() {
+        let i = vec![
+            Either3::V1(1),
+            Either3::V3('a'),
+            Either3::V2("foo"),
+            Either3::V1(2),
+        ];
+        let ((ones, twos, threes), rest): ((Vec<_>, Vec<_>, Vec<_>), Vec<_>) =
+            Either3::dispatch_partial(i, [true, false, true]);
+
+        assert_eq!(ones, [1, 2]);
+        assert!(twos.is_empty());
+        assert_eq!(threes, ['a']);
+        assert_eq!(rest, [Either3::V2("foo")]);
+    }
+}
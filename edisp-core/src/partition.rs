@@ -0,0 +1,70 @@
+//! Partitioning arbitrary values.
+//!
+//! Dispatching is not limited to iterators of enums: any value can be routed
+//! to a container, as long as a closure tells in which one it should go. This
+//! module contains the `dispatch_partition_map` function, which generalizes
+//! `Iterator::partition` to any number of containers.
+
+use crate::dispatch::Dispatch;
+
+/// Maps each value to an enum and dispatches the result.
+///
+/// The closure `f` is called on each value yielded by `iter`. The variant it
+/// returns decides in which container the value ends up. The returned enum
+/// can be any type implementing `Dispatch`, such as `Result` or the `EitherN`
+/// types.
+///
+/// ```
+/// use edisp_core::prelude::*;
+///
+/// let (evens, odds): (Vec<_>, Vec<_>) = dispatch_partition_map(1..=5, |n| {
+///     if n % 2 == 0 {
+///         Ok(n)
+///     } else {
+///         Err(n.to_string())
+///     }
+/// });
+///
+/// assert_eq!(evens, [2, 4]);
+/// assert_eq!(odds, ["1", "3", "5"]);
+/// ```
+pub fn dispatch_partition_map<I, F, E, O>(iter: I, f: F) -> O
+where
+    I: IntoIterator,
+    F: FnMut(I::Item) -> E,
+    E: Dispatch<O>,
+{
+    E::dispatch(iter.into_iter().map(f))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn dispatch_partition_map_three_way() {
+        enum Size {
+            Small(u8),
+            Medium(u16),
+            Large(u32),
+        }
+
+        implement_dispatch!(Size, Small(u8), Medium(u16), Large(u32));
+
+        let (small, medium, large): (Vec<_>, Vec<_>, Vec<_>) =
+            dispatch_partition_map(vec![1, 100_000, 300, 2], |n: u32| {
+                if n <= u8::MAX.into() {
+                    Size::Small(n as u8)
+                } else if n <= u16::MAX.into() {
+                    Size::Medium(n as u16)
+                } else {
+                    Size::Large(n)
+                }
+            });
+
+        assert_eq!(small, [1, 2]);
+        assert_eq!(medium, [300]);
+        assert_eq!(large, [100_000]);
+    }
+}
@@ -3,4 +3,48 @@
 //! This module is expected to be imported wherever the dispatching system of
 //! `edisp` is used.
 
-pub use crate::{dispatch::Dispatch, implement_dispatch, implement_dispatcher_trait, std_enums::*};
+pub use crate::{
+    bounded::{DispatchBounded, FallibleExtend, IntoRefused, Overflow, OverflowPolicy},
+    budget::{BudgetExceeded, DispatchWithBudget},
+    by_kind::{ContainerMap, DispatchByKind},
+    counts::{DispatchCounts, VariantStats},
+    dispatch::{Dispatch, DispatchIterator, Dispatched, Variants},
+    dispatcher::{DispatchInto, Dispatcher, Parts},
+    drain::DispatchDrain,
+    either::*,
+    expect::{DispatchExpecting, Expectation, UnmetExpectations, Violation},
+    find::FindEach,
+    fold::{DispatchFold, DispatchForEach},
+    implement_dispatch, implement_dispatcher_trait,
+    indexed::{DispatchWithContext, DispatchWithIndices},
+    kind::{VariantIndex, VariantKind},
+    map::DispatchMap,
+    merge::{DispatchChunked, Merge},
+    nested::{CollectNestedResult, CollectOptionResult, CollectResultOption},
+    ordered::{DispatchOrdered, Undispatch, Undispatched},
+    partial::DispatchPartial,
+    partition::dispatch_partition_map,
+    progress::DispatchWithProgress,
+    reserve::{DispatchExact, DispatchReserved, ReserveHint},
+    slice::DispatchSlice,
+    split::DispatchSplit,
+    std_enums::*,
+    take::DispatchTake,
+    try_dispatch::{TryDispatch, TryDispatchInto, TryExtend},
+    until::DispatchUntil,
+    windows::{DispatchWindows, Windows},
+};
+
+#[cfg(feature = "std")]
+pub use crate::workers::DispatchToWorkers;
+
+#[cfg(feature = "futures")]
+pub use crate::sink::{dispatch_to_sinks, ForwardToSinks};
+#[cfg(feature = "futures")]
+pub use crate::stream::{dispatch_option_stream, dispatch_result_stream, dispatch_stream};
+
+#[cfg(feature = "rayon")]
+pub use crate::parallel::ParallelDispatch;
+
+#[cfg(feature = "serde")]
+pub use crate::de::{dispatch_seq, DispatchSeed};
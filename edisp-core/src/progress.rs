@@ -0,0 +1,122 @@
+//! Reporting progress while dispatching.
+//!
+//! Dispatching a huge input may take a while. This module contains the
+//! `DispatchWithProgress` trait, which periodically calls a closure with the
+//! number of values of each variant dispatched so far, so that a progress
+//! bar can be rendered.
+
+use crate::{dispatch::Variants, either::*};
+
+/// A dispatcher trait reporting its progress.
+///
+/// This trait behaves like `Dispatch`, except that `callback` is called each
+/// time `every_n` values have been dispatched. It receives the number of
+/// values of each variant dispatched so far.
+///
+/// # Panics
+///
+/// Dispatching panics if `every_n` is zero.
+///
+/// ```
+/// use edisp_core::prelude::*;
+///
+/// let iter = vec![Ok(1), Err("foo"), Ok(2), Ok(3), Err("bar")].into_iter();
+/// let mut reports = Vec::new();
+/// let (some_oks, some_errs): (Vec<_>, Vec<_>) =
+///     Result::dispatch_with_progress(iter, 2, |counts| reports.push(*counts));
+///
+/// assert_eq!(some_oks, [1, 2, 3]);
+/// assert_eq!(some_errs, ["foo", "bar"]);
+/// assert_eq!(reports, [[1, 1], [3, 1]]);
+/// ```
+pub trait DispatchWithProgress<O>
+where
+    Self: Variants,
+    Self::Either: EitherN,
+{
+    /// Performs dispatching, reporting progress every `every_n` values.
+    fn dispatch_with_progress<I, F>(iter: I, every_n: usize, callback: F) -> O
+    where
+        I: IntoIterator<Item = Self>,
+        F: FnMut(&<Self::Either as EitherN>::Counts);
+}
+
+/// Implements `DispatchWithProgress` for every enum with a given number of
+/// variants.
+macro_rules! implement_dispatch_with_progress_for_arity {
+    (
+        $either:ident,
+        $arity:literal,
+        $( (
+            $variant:ident,
+            $t:ident,
+            $c:ident,
+            $c_name:ident,
+            $f:ident,
+            $f_name:ident,
+            $idx:tt
+        ) ),+ $( , )?
+    ) => {
+        impl<E, $( $t, )+ $( $c, )+> DispatchWithProgress<( $( $c, )+ )> for E
+        where
+            E: Variants<Either = $either< $( $t, )+ >>,
+            $( $c: Default + Extend<$t>, )+
+        {
+            fn dispatch_with_progress<I, F>(
+                iter: I,
+                every_n: usize,
+                mut callback: F,
+            ) -> ( $( $c, )+ )
+            where
+                I: IntoIterator<Item = Self>,
+                F: FnMut(&[usize; $arity]),
+            {
+                assert!(every_n != 0, "progress interval must be non-zero");
+
+                $( let mut $c_name = $c::default(); )+
+                let mut counts = [0; $arity];
+
+                for (index, element) in iter.into_iter().enumerate() {
+                    match element.into_either() {
+                        $(
+                            $either::$variant(value) => {
+                                counts[$idx] += 1;
+                                $c_name.extend(Some(value));
+                            }
+                        )+
+                    }
+
+                    if (index + 1) % every_n == 0 {
+                        callback(&counts);
+                    }
+                }
+
+                ( $( $c_name, )+ )
+            }
+        }
+    };
+}
+
+for_each_arity!(implement_dispatch_with_progress_for_arity);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dispatch_with_progress_reports_running_counts() {
+        let i = (0..10).map(|n| {
+            if n % 3 == 0 {
+                Either3::<_, _, ()>::V1(n)
+            } else {
+                Either3::V2(n)
+            }
+        });
+        let mut reports = Vec::new();
+        let (_, _, nothing): (Vec<_>, Vec<_>, Vec<_>) =
+            Either3::dispatch_with_progress(i, 4, |counts| reports.push(*counts));
+
+        assert!(nothing.is_empty());
+        assert_eq!(reports, [[2, 2, 0], [3, 5, 0]]);
+    }
+}
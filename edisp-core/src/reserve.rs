@@ -0,0 +1,316 @@
+//! Capacity-aware dispatching.
+//!
+//! Growing a container one value at a time may lead to many reallocations
+//! when a lot of values are dispatched. This module contains the
+//! `ReserveHint` trait, implemented by containers which can allocate memory
+//! ahead of time, the `DispatchReserved` trait, which uses the iterator size
+//! hint to do so before dispatching, and the `DispatchExact` trait, which
+//! uses an exact length or capacities given by the caller.
+
+use alloc::collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque};
+use alloc::string::String;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::hash::{BuildHasher, Hash};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+
+use crate::{dispatch::Variants, either::*};
+
+/// A container which can reserve capacity for future values.
+///
+/// Containers which can not allocate memory ahead of time, such as
+/// `BTreeMap` or `LinkedList`, implement this trait as a no-op, so that they
+/// can still be used with `DispatchReserved`.
+pub trait ReserveHint {
+    /// Reserves capacity for at least `additional` more values.
+    fn reserve(&mut self, additional: usize);
+
+    /// Creates an empty container, with capacity for at least `capacity`
+    /// values.
+    ///
+    /// This is the constructor used by the capacity-aware dispatchers. By
+    /// default, it creates a container with `Default::default` and then
+    /// reserves capacity, which containers allocating memory differently may
+    /// override.
+    ///
+    /// ```
+    /// use edisp_core::prelude::*;
+    ///
+    /// let v: Vec<u8> = ReserveHint::create_with_capacity(16);
+    ///
+    /// assert!(v.is_empty());
+    /// assert!(v.capacity() >= 16);
+    /// ```
+    fn create_with_capacity(capacity: usize) -> Self
+    where
+        Self: Default + Sized,
+    {
+        let mut container = Self::default();
+        container.reserve(capacity);
+        container
+    }
+}
+
+/// Implements `ReserveHint` for a collection which has a `reserve` method.
+macro_rules! implement_reserve_hint {
+    ( $( [ $( $generics:tt )* ] $collection:ty ),+ $( , )? ) => {
+        $(
+            impl< $( $generics )* > ReserveHint for $collection {
+                fn reserve(&mut self, additional: usize) {
+                    <$collection>::reserve(self, additional);
+                }
+            }
+        )+
+    };
+}
+
+implement_reserve_hint! {
+    [T] Vec<T>,
+    [T] VecDeque<T>,
+    [T: Ord] BinaryHeap<T>,
+    [] String,
+}
+
+#[cfg(feature = "std")]
+implement_reserve_hint! {
+    [T: Eq + Hash, S: BuildHasher] HashSet<T, S>,
+    [K: Eq + Hash, V, S: BuildHasher] HashMap<K, V, S>,
+}
+
+/// Implements `ReserveHint` as a no-op for a collection.
+macro_rules! implement_noop_reserve_hint {
+    ( $( [ $( $generics:tt )* ] $collection:ty ),+ $( , )? ) => {
+        $(
+            impl< $( $generics )* > ReserveHint for $collection {
+                fn reserve(&mut self, _additional: usize) {}
+            }
+        )+
+    };
+}
+
+implement_noop_reserve_hint! {
+    [T] LinkedList<T>,
+    [T] BTreeSet<T>,
+    [K, V] BTreeMap<K, V>,
+}
+
+/// A capacity-aware dispatcher trait.
+///
+/// This trait behaves like `Dispatch`, except that every container must
+/// implement `ReserveHint`. Before dispatching, each container reserves
+/// capacity for the lower bound of the iterator size hint, as there is no way
+/// to know in advance how values will be distributed.
+///
+/// The memory allocated is therefore proportional to that lower bound times
+/// the number of variants. `DispatchExact::dispatch_with_capacities` allows to
+/// reserve a different capacity in each container when the distribution is
+/// known.
+///
+/// ```
+/// use edisp_core::prelude::*;
+///
+/// let iter = vec![Ok(1), Err("foo"), Ok(2)].into_iter();
+/// let (some_oks, some_errs): (Vec<_>, Vec<_>) = Result::dispatch_reserved(iter);
+///
+/// assert_eq!(some_oks, [1, 2]);
+/// assert_eq!(some_errs, ["foo"]);
+/// assert!(some_errs.capacity() >= 3);
+/// ```
+pub trait DispatchReserved<O>
+where
+    Self: Sized,
+{
+    /// Performs dispatching, reserving capacity beforehand.
+    fn dispatch_reserved<I: IntoIterator<Item = Self>>(iter: I) -> O;
+}
+
+/// Implements `DispatchReserved` for every enum with a given number of
+/// variants.
+macro_rules! implement_dispatch_reserved_for_arity {
+    (
+        $either:ident,
+        $arity:literal,
+        $( (
+            $variant:ident,
+            $t:ident,
+            $c:ident,
+            $c_name:ident,
+            $f:ident,
+            $f_name:ident,
+            $idx:tt
+        ) ),+ $( , )?
+    ) => {
+        impl<E, $( $t, )+ $( $c, )+> DispatchReserved<( $( $c, )+ )> for E
+        where
+            E: Variants<Either = $either< $( $t, )+ >>,
+            $( $c: Default + Extend<$t> + ReserveHint, )+
+        {
+            fn dispatch_reserved<I: IntoIterator<Item = Self>>(iter: I) -> ( $( $c, )+ ) {
+                let iter = iter.into_iter();
+                let (lower_bound, _) = iter.size_hint();
+
+                $( let mut $c_name = $c::create_with_capacity(lower_bound); )+
+
+                for element in iter {
+                    match element.into_either() {
+                        $( $either::$variant(value) => $c_name.extend(Some(value)), )+
+                    }
+                }
+
+                ( $( $c_name, )+ )
+            }
+        }
+    };
+}
+
+for_each_arity!(implement_dispatch_reserved_for_arity);
+
+/// A dispatcher trait allocating containers once.
+///
+/// Every container must implement `ReserveHint`. Capacity is reserved once,
+/// before dispatching, so that containers never grow if the reserved
+/// capacity is large enough.
+///
+/// ```
+/// use edisp_core::prelude::*;
+///
+/// let iter = vec![Ok(1), Err("foo"), Ok(2)].into_iter();
+/// let (some_oks, some_errs): (Vec<_>, Vec<_>) = Result::dispatch_with_capacities(iter, [2, 1]);
+///
+/// assert_eq!(some_oks, [1, 2]);
+/// assert_eq!(some_errs, ["foo"]);
+/// assert!(some_oks.capacity() >= 2);
+/// ```
+pub trait DispatchExact<O>
+where
+    Self: Variants,
+    Self::Either: EitherN,
+{
+    /// Performs dispatching, reserving capacity for the exact length of the
+    /// iterator in every container.
+    ///
+    /// As there is no way to know in advance how values will be
+    /// distributed, each container reserves capacity for every value: the
+    /// memory allocated is proportional to the length of the iterator times
+    /// the number of variants. Use `dispatch_with_capacities` when the
+    /// distribution is known.
+    fn dispatch_exact<I>(iter: I) -> O
+    where
+        I: IntoIterator<Item = Self>,
+        I::IntoIter: ExactSizeIterator;
+
+    /// Performs dispatching, reserving the given capacity in each container.
+    ///
+    /// Containers receiving more values than their capacity grow as usual.
+    fn dispatch_with_capacities<I: IntoIterator<Item = Self>>(
+        iter: I,
+        capacities: <Self::Either as EitherN>::Counts,
+    ) -> O;
+}
+
+/// Implements `DispatchExact` for every enum with a given number of variants.
+macro_rules! implement_dispatch_exact_for_arity {
+    (
+        $either:ident,
+        $arity:literal,
+        $( (
+            $variant:ident,
+            $t:ident,
+            $c:ident,
+            $c_name:ident,
+            $f:ident,
+            $f_name:ident,
+            $idx:tt
+        ) ),+ $( , )?
+    ) => {
+        impl<E, $( $t, )+ $( $c, )+> DispatchExact<( $( $c, )+ )> for E
+        where
+            E: Variants<Either = $either< $( $t, )+ >>,
+            $( $c: Default + Extend<$t> + ReserveHint, )+
+        {
+            fn dispatch_exact<I>(iter: I) -> ( $( $c, )+ )
+            where
+                I: IntoIterator<Item = Self>,
+                I::IntoIter: ExactSizeIterator,
+            {
+                let iter = iter.into_iter();
+                let len = iter.len();
+
+                Self::dispatch_with_capacities(iter, [len; $arity])
+            }
+
+            fn dispatch_with_capacities<I: IntoIterator<Item = Self>>(
+                iter: I,
+                capacities: [usize; $arity],
+            ) -> ( $( $c, )+ ) {
+                $( let mut $c_name = $c::create_with_capacity(capacities[$idx]); )+
+
+                for element in iter {
+                    match element.into_either() {
+                        $( $either::$variant(value) => $c_name.extend(Some(value)), )+
+                    }
+                }
+
+                ( $( $c_name, )+ )
+            }
+        }
+    };
+}
+
+for_each_arity!(implement_dispatch_exact_for_arity);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dispatch_reserved_reserves_lower_bound() {
+        let i = vec![Ok(42), Err("foo"), Ok(101), Ok(0)].into_iter();
+        let (some_oks, some_errs): (Vec<_>, BTreeSet<_>) = Result::dispatch_reserved(i);
+
+        assert_eq!(some_oks, [42, 101, 0]);
+        assert!(some_oks.capacity() >= 4);
+        assert_eq!(some_errs.into_iter().collect::<Vec<_>>(), ["foo"]);
+    }
+
+    #[derive(Default)]
+    struct Preallocated(Vec<u8>);
+
+    impl Extend<u8> for Preallocated {
+        fn extend<I: IntoIterator<Item = u8>>(&mut self, iter: I) {
+            self.0.extend(iter);
+        }
+    }
+
+    impl ReserveHint for Preallocated {
+        fn reserve(&mut self, _additional: usize) {
+            unreachable!("containers must be created with `create_with_capacity`");
+        }
+
+        fn create_with_capacity(capacity: usize) -> Preallocated {
+            Preallocated(Vec::with_capacity(capacity * 2))
+        }
+    }
+
+    #[test]
+    fn dispatch_with_capacities_uses_constructor() {
+        let i = vec![Ok(1), Err(2), Ok(3)];
+        let (some_oks, some_errs): (Preallocated, Preallocated) =
+            Result::dispatch_with_capacities(i, [2, 1]);
+
+        assert_eq!(some_oks.0, [1, 3]);
+        assert!(some_oks.0.capacity() >= 4);
+        assert_eq!(some_errs.0, [2]);
+    }
+
+    #[test]
+    fn dispatch_exact_reserves_length() {
+        let i = vec![Err('a'), Ok(42), Err('b')];
+        let (some_oks, some_errs): (Vec<_>, String) = Result::dispatch_exact(i);
+
+        assert_eq!(some_oks, [42]);
+        assert!(some_oks.capacity() >= 3);
+        assert_eq!(some_errs, "ab");
+    }
+}
@@ -0,0 +1,206 @@
+//! Forwarding values to asynchronous sinks.
+//!
+//! In an asynchronous pipeline, each variant may be consumed by its own task,
+//! listening on its own channel. This module contains the `dispatch_to_sinks`
+//! function, which forwards each value yielded by a stream to the `Sink`
+//! matching its variant, and the `ForwardToSinks` trait on which it is built.
+//!
+//! This module is only available with the `futures` feature.
+
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures::future;
+use futures::sink::Sink;
+use futures::stream::{Stream, StreamExt};
+
+use crate::{dispatch::Variants, either::*};
+
+/// Sends values to the sink matching their variant.
+///
+/// The generic type `K` should be a tuple whose arity is equal to the number
+/// of variants of the implementor, and should contain only sinks which
+/// implement `Sink` and `Unpin`. This trait is implemented for every type
+/// implementing `Variants`.
+pub trait ForwardToSinks<K>
+where
+    Self: Sized,
+{
+    /// The error returned when a sink fails. It is an `EitherN` indicating
+    /// which sink failed, and why.
+    type Error;
+
+    /// Sends `value` to its sink, once the sink is ready to receive it.
+    ///
+    /// `value` is taken when it is sent, and left untouched otherwise.
+    fn poll_forward(
+        value: &mut Option<Self>,
+        sinks: &mut K,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>>;
+
+    /// Flushes every sink.
+    fn poll_flush_all(sinks: &mut K, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>>;
+}
+
+/// Implements `ForwardToSinks` for every enum with a given number of variants.
+macro_rules! implement_forward_to_sinks_for_arity {
+    (
+        $either:ident,
+        $arity:literal,
+        $( (
+            $variant:ident,
+            $t:ident,
+            $c:ident,
+            $c_name:ident,
+            $f:ident,
+            $f_name:ident,
+            $idx:tt
+        ) ),+ $( , )?
+    ) => {
+        impl<E, $( $t, )+ $( $c, )+> ForwardToSinks<( $( $c, )+ )> for E
+        where
+            E: Variants<Either = $either< $( $t, )+ >>,
+            $( $c: Sink<$t> + Unpin, )+
+        {
+            type Error = $either< $( $c::Error, )+ >;
+
+            fn poll_forward(
+                value: &mut Option<Self>,
+                sinks: &mut ( $( $c, )+ ),
+                cx: &mut Context<'_>,
+            ) -> Poll<Result<(), Self::Error>> {
+                let either = match value.take() {
+                    Some(value) => value.into_either(),
+                    None => return Poll::Ready(Ok(())),
+                };
+
+                match either {
+                    $(
+                        $either::$variant(payload) => {
+                            let mut sink = Pin::new(&mut sinks.$idx);
+
+                            match sink.as_mut().poll_ready(cx) {
+                                Poll::Ready(Ok(())) => Poll::Ready(
+                                    sink.start_send(payload).map_err($either::$variant),
+                                ),
+                                Poll::Ready(Err(error)) => {
+                                    Poll::Ready(Err($either::$variant(error)))
+                                }
+                                Poll::Pending => {
+                                    *value = Some(E::from_either($either::$variant(payload)));
+                                    Poll::Pending
+                                }
+                            }
+                        }
+                    )+
+                }
+            }
+
+            fn poll_flush_all(
+                sinks: &mut ( $( $c, )+ ),
+                cx: &mut Context<'_>,
+            ) -> Poll<Result<(), Self::Error>> {
+                let mut ready = true;
+
+                $(
+                    match Pin::new(&mut sinks.$idx).poll_flush(cx) {
+                        Poll::Ready(Ok(())) => {}
+                        Poll::Ready(Err(error)) => return Poll::Ready(Err($either::$variant(error))),
+                        Poll::Pending => ready = false,
+                    }
+                )+
+
+                if ready {
+                    Poll::Ready(Ok(()))
+                } else {
+                    Poll::Pending
+                }
+            }
+        }
+    };
+}
+
+for_each_arity!(implement_forward_to_sinks_for_arity);
+
+/// Forwards each value yielded by a stream to the sink matching its variant.
+///
+/// Each value is sent as soon as its sink is ready to receive it. A slow sink
+/// therefore slows down the whole stream, which applies backpressure to the
+/// producer. Sinks are flushed once the stream is exhausted, and given back.
+///
+/// # Errors
+///
+/// This function stops as soon as a sink fails. The returned error is an
+/// `EitherN` indicating which sink failed, and why.
+///
+/// ```
+/// use edisp_core::prelude::*;
+/// use futures::{channel::mpsc, executor::block_on, stream, StreamExt};
+///
+/// let (ok_tx, ok_rx) = mpsc::unbounded();
+/// let (err_tx, err_rx) = mpsc::unbounded();
+///
+/// let stream = stream::iter(vec![Ok(1), Err("foo"), Ok(2)]);
+/// block_on(dispatch_to_sinks(stream, (ok_tx, err_tx))).unwrap();
+///
+/// assert_eq!(block_on(ok_rx.collect::<Vec<_>>()), [1, 2]);
+/// assert_eq!(block_on(err_rx.collect::<Vec<_>>()), ["foo"]);
+/// ```
+pub async fn dispatch_to_sinks<S, E, K>(stream: S, mut sinks: K) -> Result<K, E::Error>
+where
+    S: Stream<Item = E>,
+    E: ForwardToSinks<K>,
+{
+    futures::pin_mut!(stream);
+
+    while let Some(value) = stream.next().await {
+        let mut value = Some(value);
+        future::poll_fn(|cx| E::poll_forward(&mut value, &mut sinks, cx)).await?;
+    }
+
+    future::poll_fn(|cx| E::poll_flush_all(&mut sinks, cx)).await?;
+
+    Ok(sinks)
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{channel::mpsc, executor::block_on, join, stream};
+
+    use super::*;
+
+    #[test]
+    fn dispatch_to_sinks_with_backpressure() {
+        let (ok_tx, ok_rx) = mpsc::channel(0);
+        let (err_tx, err_rx) = mpsc::channel(0);
+
+        let stream = stream::iter((0..10).map(|n| if n % 3 == 0 { Err(n) } else { Ok(n) }));
+        let forward = async move {
+            dispatch_to_sinks(stream, (ok_tx, err_tx)).await.unwrap();
+        };
+
+        let ((), some_oks, some_errs) = block_on(async {
+            join!(
+                forward,
+                ok_rx.collect::<Vec<_>>(),
+                err_rx.collect::<Vec<_>>()
+            )
+        });
+
+        assert_eq!(some_oks, [1, 2, 4, 5, 7, 8]);
+        assert_eq!(some_errs, [0, 3, 6, 9]);
+    }
+
+    #[test]
+    fn dispatch_to_sinks_closed_sink() {
+        let (ok_tx, ok_rx) = mpsc::unbounded::<i32>();
+        let (err_tx, _err_rx) = mpsc::unbounded();
+        drop(ok_rx);
+
+        let stream = stream::iter(vec![Err('a'), Ok(1), Err('b')]);
+        let error = block_on(dispatch_to_sinks(stream, (ok_tx, err_tx))).unwrap_err();
+
+        assert!(matches!(error, Either2::V1(error) if error.is_disconnected()));
+    }
+}
@@ -0,0 +1,58 @@
+//! Dispatching slices.
+//!
+//! Application code often holds values in a `Vec` or an array rather than in
+//! an iterator. As `Dispatch` accepts any `IntoIterator`, both can be
+//! dispatched by value directly. This module contains the `DispatchSlice`
+//! trait, which dispatches borrowed slices by cloning their values.
+
+use crate::dispatch::Dispatch;
+
+/// Allows to dispatch the values of a slice.
+///
+/// This trait is implemented for every slice, and can therefore be used on
+/// arrays and `Vec`s as well. Values are cloned, the slice is left untouched.
+///
+/// ```
+/// use edisp_core::prelude::*;
+///
+/// let values = [Ok(1), Err("foo"), Ok(2)];
+///
+/// let (some_oks, some_errs): (Vec<_>, Vec<_>) = values.dispatch_slice();
+/// assert_eq!(some_oks, [1, 2]);
+/// assert_eq!(some_errs, ["foo"]);
+///
+/// // Arrays and `Vec`s can be dispatched by value without cloning.
+/// let (some_oks, some_errs): (Vec<_>, Vec<_>) = Result::dispatch(values);
+/// assert_eq!(some_oks, [1, 2]);
+/// assert_eq!(some_errs, ["foo"]);
+/// ```
+pub trait DispatchSlice<E> {
+    /// Dispatches clones of the values of the slice.
+    fn dispatch_slice<O>(&self) -> O
+    where
+        E: Clone + Dispatch<O>;
+}
+
+impl<E> DispatchSlice<E> for [E] {
+    fn dispatch_slice<O>(&self) -> O
+    where
+        E: Clone + Dispatch<O>,
+    {
+        E::dispatch(self.iter().cloned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dispatch_subslice() {
+        let values = [Err('a'), Ok(42), Err('b'), Ok(101)];
+        let (some_oks, some_errs): (Vec<_>, String) = values[..3].dispatch_slice();
+
+        assert_eq!(some_oks, [42]);
+        assert_eq!(some_errs, "ab");
+        assert_eq!(values.len(), 4);
+    }
+}
@@ -0,0 +1,233 @@
+//! Lazy dispatching.
+//!
+//! Collecting every value before processing them is not always desirable.
+//! This module contains the `DispatchSplit` trait, which splits an iterator
+//! into one lazy iterator per variant. Each of them pulls values from the
+//! original iterator on demand, and buffers values of other variants until
+//! they are requested. Values of a variant whose iterator has been dropped
+//! are dropped instead of being buffered.
+
+use alloc::collections::VecDeque;
+use alloc::rc::Rc;
+use core::cell::RefCell;
+
+use crate::{dispatch::Variants, dispatcher::DispatchInto, either::*};
+
+/// The values of a single variant which have been pulled from the original
+/// iterator but not yielded yet.
+///
+/// It is used as a container by `SplitIter`. Once the iterator of its variant
+/// is dropped, it is marked as not alive and drops the values it receives.
+#[derive(Debug)]
+pub struct SplitBuffer<T> {
+    values: VecDeque<T>,
+    alive: bool,
+}
+
+impl<T> SplitBuffer<T> {
+    /// Marks the buffer as not alive, and drops its values.
+    fn close(&mut self) {
+        self.alive = false;
+        self.values.clear();
+    }
+}
+
+impl<T> Default for SplitBuffer<T> {
+    fn default() -> SplitBuffer<T> {
+        SplitBuffer {
+            values: VecDeque::new(),
+            alive: true,
+        }
+    }
+}
+
+impl<T> Extend<T> for SplitBuffer<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        if self.alive {
+            self.values.extend(iter);
+        }
+    }
+}
+
+/// The state shared by every iterator returned by `DispatchSplit`.
+#[derive(Debug)]
+struct SplitSource<I, B> {
+    /// The original iterator.
+    iter: I,
+    /// One `SplitBuffer` per variant.
+    buffers: B,
+}
+
+impl<I, B> SplitSource<I, B>
+where
+    I: Iterator,
+    I::Item: DispatchInto<B>,
+{
+    /// Pulls values from the original iterator until `pop` returns a value.
+    fn pull<T>(&mut self, pop: impl Fn(&mut B) -> Option<T>) -> Option<T> {
+        loop {
+            if let Some(value) = pop(&mut self.buffers) {
+                return Some(value);
+            }
+
+            self.iter.next()?.dispatch_into(&mut self.buffers);
+        }
+    }
+}
+
+/// An iterator over the values of a single variant.
+///
+/// It is returned by `DispatchSplit::split`. `I` is the original iterator,
+/// `B` is the tuple of buffers shared by the iterators of every variant, and
+/// `T` is the type of the values yielded.
+pub struct SplitIter<I, B, T> {
+    source: Rc<RefCell<SplitSource<I, B>>>,
+    next: fn(&mut SplitSource<I, B>) -> Option<T>,
+    close: fn(&mut SplitSource<I, B>),
+}
+
+impl<I, B, T> Iterator for SplitIter<I, B, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        (self.next)(&mut self.source.borrow_mut())
+    }
+}
+
+impl<I, B, T> Drop for SplitIter<I, B, T> {
+    fn drop(&mut self) {
+        (self.close)(&mut self.source.borrow_mut());
+    }
+}
+
+/// A trait splitting an iterator into one lazy iterator per variant.
+///
+/// The generic type `O` is a tuple of `SplitIter`s, one for each variant of
+/// the implementor. Values are pulled from the original iterator only when an
+/// iterator needs them. Values of other variants met in the meantime are
+/// buffered, so memory usage depends on how evenly the returned iterators
+/// are consumed. Values of a variant whose iterator has been dropped are not
+/// buffered.
+///
+/// The returned iterators share the original iterator through a reference
+/// counted pointer, so they can not be sent to other threads.
+///
+/// ```
+/// use edisp_core::prelude::*;
+///
+/// let iter = vec![Ok(1), Err("foo"), Ok(2), Err("bar")].into_iter();
+/// let (mut oks, mut errs) = Result::split(iter);
+///
+/// assert_eq!(errs.next(), Some("foo"));
+/// assert_eq!(oks.collect::<Vec<_>>(), [1, 2]);
+/// assert_eq!(errs.next(), Some("bar"));
+/// assert_eq!(errs.next(), None);
+/// ```
+pub trait DispatchSplit<I, O>
+where
+    Self: Sized,
+    I: IntoIterator<Item = Self>,
+{
+    /// Splits the iterator.
+    fn split(iter: I) -> O;
+}
+
+/// Implements `DispatchSplit` for every enum with a given number of variants.
+macro_rules! implement_dispatch_split_for_arity {
+    (
+        $either:ident,
+        $arity:literal,
+        $( (
+            $variant:ident,
+            $t:ident,
+            $c:ident,
+            $c_name:ident,
+            $f:ident,
+            $f_name:ident,
+            $idx:tt
+        ) ),+ $( , )?
+    ) => {
+        implement_dispatch_split_for_arity!(
+            @impl $either,
+            ( $( SplitBuffer<$t>, )+ ),
+            $( ($t, $idx) ),+
+        );
+    };
+
+    (@impl $either:ident, $buffers:ty, $( ($t:ident, $idx:tt) ),+) => {
+        impl<E, I, $( $t, )+>
+            DispatchSplit<I, ( $( SplitIter<I::IntoIter, $buffers, $t>, )+ )> for E
+        where
+            E: Variants<Either = $either< $( $t, )+ >>,
+            I: IntoIterator<Item = E>,
+        {
+            fn split(iter: I) -> ( $( SplitIter<I::IntoIter, $buffers, $t>, )+ ) {
+                let source = Rc::new(RefCell::new(SplitSource {
+                    iter: iter.into_iter(),
+                    buffers: <$buffers>::default(),
+                }));
+
+                (
+                    $(
+                        SplitIter {
+                            source: Rc::clone(&source),
+                            next: |source| source.pull(|buffers| buffers.$idx.values.pop_front()),
+                            close: |source| source.buffers.$idx.close(),
+                        },
+                    )+
+                )
+            }
+        }
+    };
+}
+
+for_each_arity!(implement_dispatch_split_for_arity);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_is_lazy() {
+        use core::cell::Cell;
+
+        let pulled = Cell::new(0);
+        let i = vec![Either3::V1(1), Either3::V2('a'), Either3::V3("foo")]
+            .into_iter()
+            .inspect(|_| pulled.set(pulled.get() + 1));
+        let (mut some_v1, _, mut some_v3) = Either3::split(i);
+
+        assert_eq!(some_v1.next(), Some(1));
+        assert_eq!(pulled.get(), 1);
+
+        assert_eq!(some_v1.next(), None);
+        assert_eq!(pulled.get(), 3);
+
+        assert_eq!(some_v3.next(), Some("foo"));
+        assert_eq!(some_v3.next(), None);
+    }
+
+    #[test]
+    fn split_drops_values_of_dropped_iterators() {
+        let i = vec![Err('a'), Ok(1), Ok(2), Err('b')].into_iter();
+        let (mut some_oks, some_errs) = Result::split(i);
+
+        assert_eq!(some_oks.next(), Some(1));
+        assert_eq!(some_oks.source.borrow().buffers.1.values, ['a']);
+
+        drop(some_errs);
+        assert!(some_oks.source.borrow().buffers.1.values.is_empty());
+
+        assert_eq!(some_oks.collect::<Vec<_>>(), [2]);
+    }
+
+    #[test]
+    fn split_does_not_buffer_ignored_variants() {
+        let i = vec![Ok(1), Err('a'), Err('b'), Ok(2)].into_iter();
+        let (mut some_oks, _) = Result::split(i);
+
+        assert_eq!(some_oks.next(), Some(1));
+        assert_eq!(some_oks.next(), Some(2));
+        assert!(some_oks.source.borrow().buffers.1.values.is_empty());
+    }
+}
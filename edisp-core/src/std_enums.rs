@@ -3,94 +3,518 @@
 //!
 //! Every `std` enum should additionaly have a custom trait entitled `CollectE`
 //! (`E` being the name of the enum), which can be used as an iterator adapter.
+//! These traits are generated by the `implement_collect_trait` macro, so that
+//! they all have the same shape.
 //!
 //! The following list contains every enum available in the standard library
 //! and whether if the `Dispatch` trait has been implemented for it:
 //!   - `Cow` (done),
 //!   - `Entry` (both in `hash_map` and in `btree_map`) (todo),
-//!   - `VarError` (todo),
-//!   - `SeekFrom` (todo),
-//!   - `IpAddr` (todo),
-//!   - `SocketAddr` (todo),
-//!   - `Bound` (todo),
-//!   - `Option` (todo),
-//!   - `Component` (todo),
+//!   - `VarError` (done),
+//!   - `SeekFrom` (done),
+//!   - `IpAddr` (done),
+//!   - `SocketAddr` (done),
+//!   - `Bound` (done),
+//!   - `Option` (done),
+//!   - `Component` (done),
 //!   - `Prefix` (todo),
 //!   - `Result` (done),
-//!   - `TryLockError` (todo),
-//!   - `Poll` (todo),
+//!   - `TryLockError` (done),
+//!   - `Poll` (done),
+//!   - `ControlFlow` (done),
+//!   - `TryRecvError` (done),
+//!   - `RecvTimeoutError` (done),
+//!   - `TrySendError` (done),
+//!   - `Ordering` (done),
+//!   - `FpCategory` (done),
+//!   - `Alignment` (done),
 //!
 //! Some enums may not benefit from the implementation of `Dispatch` as such,
 //! they have been removed from this list.
+//!
+//! Some groups of enums are behind cargo features, all of them being enabled
+//! by default:
+//!   - `net`: `IpAddr` and `SocketAddr`,
+//!   - `fs`: `SeekFrom` and `Component`,
+//!   - `sync`: `TryLockError` and the `mpsc` errors.
+//!
+//! Some enums defined in other crates are supported as well, each behind a
+//! cargo feature of the same name, disabled by default:
+//!   - `either`: `Either`,
+//!   - `serde_json`: `Value`.
 
-use std::borrow::Cow;
+use alloc::borrow::{Cow, ToOwned};
+use core::{
+    cmp::Ordering,
+    fmt::Alignment,
+    num::FpCategory,
+    ops::{Bound, ControlFlow},
+    task::Poll,
+};
+#[cfg(feature = "std")]
+use std::{env::VarError, ffi::OsString};
 
 use crate::prelude::*;
 
+/// Generates the `CollectE` adapter trait of a `std` enum, and implements it
+/// for every iterator over this enum.
+///
+/// The trait gets a `dispatch_*` method, which collects values in one
+/// container per variant, and a `count_*` method, which only counts them. The
+/// container type parameters and the type they must be extended with are
+/// given for each variant. Any additional item is added to the trait as is,
+/// and must therefore be a provided method.
+macro_rules! implement_collect_trait {
+    (@one $c:ident) => {
+        1
+    };
+
+    (
+        $( #[$attr:meta] )*
+        $trait_name:ident $( < $( $g:tt ),+ > )? for $enum_ty:ty
+        $( where [ $( $bound:tt )* ] )?
+        {
+            fn $dispatch:ident( $( $c:ident: $t:ty ),+ $( , )? );
+            fn $count:ident;
+            $( $extra:tt )*
+        }
+    ) => {
+        $( #[$attr] )*
+        pub trait $trait_name $( < $( $g ),+ > )?: Iterator<Item = $enum_ty> + Sized
+        $( where $( $bound )* )?
+        {
+            /// Collects values and dispatch them.
+            fn $dispatch< $( $c, )+ >(self) -> ( $( $c, )+ )
+            where
+                $( $c: Default + Extend<$t>, )+
+            {
+                <$enum_ty as crate::dispatch::Dispatch<( $( $c, )+ )>>::dispatch(self)
+            }
+
+            /// Counts the values of each variant, without collecting them.
+            fn $count(self) -> [usize; 0 $( + implement_collect_trait!(@one $c) )+] {
+                <$enum_ty as crate::counts::DispatchCounts>::dispatch_counts(self)
+            }
+
+            $( $extra )*
+        }
+
+        impl< $( $( $g, )+ )? I > $trait_name $( < $( $g ),+ > )? for I
+        where
+            I: Iterator<Item = $enum_ty>,
+            $( $( $bound )* )?
+        {
+        }
+    };
+}
+
+#[cfg(feature = "either")]
+mod either;
+#[cfg(feature = "fs")]
+mod fs;
+#[cfg(feature = "net")]
+mod net;
+#[cfg(feature = "serde_json")]
+mod serde_json;
+#[cfg(feature = "sync")]
+mod sync;
+
+#[cfg(feature = "either")]
+pub use self::either::*;
+#[cfg(feature = "serde_json")]
+pub use self::serde_json::*;
+#[cfg(feature = "fs")]
+pub use fs::*;
+#[cfg(feature = "net")]
+pub use net::*;
+#[cfg(feature = "sync")]
+pub use sync::*;
+
 implement_dispatch!(Result<T, E>, Ok(T), Err(E));
 
-/// Allows to collect values from an iterator by dispatching `Ok` variants
-/// and `Err` variants in two different containers.
-pub trait CollectResult<A, B> {
-    /// Collects values and dispatch them.
-    fn dispatch_result<C: Default + Extend<A>, D: Default + Extend<B>>(self) -> (C, D);
+implement_collect_trait! {
+    /// Allows to collect values from an iterator by dispatching `Ok` variants
+    /// and `Err` variants in two different containers.
+    CollectResult<T, E> for Result<T, E> {
+        fn dispatch_result(C: T, D: E);
+        fn count_results;
+
+        /// Collects `Ok` values, stopping at the first `Err` variant.
+        ///
+        /// This behaves like `collect::<Result<C, _>>()`, except that the error
+        /// comes with the number of `Ok` values which were collected before it.
+        /// The iterator is not consumed any further after the first error.
+        ///
+        /// ```
+        /// use edisp_core::prelude::*;
+        ///
+        /// let iter = vec![Ok(1), Ok(2), Err("foo"), Ok(3)].into_iter();
+        /// let collapsed: Result<Vec<_>, _> = iter.dispatch_result_collapse();
+        ///
+        /// assert_eq!(collapsed, Err(("foo", 2)));
+        /// ```
+        fn dispatch_result_collapse<C: Default + Extend<T>>(self) -> Result<C, (E, usize)> {
+            let mut oks = C::default();
+            let mut count = 0;
+
+            for value in self {
+                match value {
+                    Ok(value) => {
+                        oks.extend(Some(value));
+                        count += 1;
+                    }
+                    Err(error) => return Err((error, count)),
+                }
+            }
+
+            Ok(oks)
+        }
+    }
 }
 
-impl<T, E, I: Iterator<Item = Result<T, E>>> CollectResult<T, E> for I {
-    fn dispatch_result<C: Default + Extend<T>, D: Default + Extend<E>>(self) -> (C, D) {
-        use crate::prelude::*;
+implement_dispatch!(Option<T>, Some(T), None);
 
-        Result::dispatch(self)
+implement_collect_trait! {
+    /// Allows to collect values from an iterator by dispatching `Some` variants
+    /// and `None` variants in two different containers.
+    ///
+    /// As `None` holds no value, a `()` is added to the second container each
+    /// time it is met. Using `containers::Count` as second container allows to
+    /// get the number of `None` variants directly.
+    ///
+    /// ```
+    /// use edisp_core::{containers::Count, prelude::*};
+    ///
+    /// let iter = vec![Some(1), None, Some(2), None, None].into_iter();
+    /// let (somes, nones): (Vec<_>, Count) = iter.dispatch_option();
+    ///
+    /// assert_eq!(somes, [1, 2]);
+    /// assert_eq!(nones.into_inner(), 3);
+    /// ```
+    CollectOption<T> for Option<T> {
+        fn dispatch_option(C: T, D: ());
+        fn count_options;
     }
 }
 
-impl<'a, B: 'a + ToOwned + ?Sized, C, D> Dispatch<(C, D)> for Cow<'a, B>
-where
-    Self: Sized,
-    C: Default + Extend<&'a B>,
-    D: Default + Extend<<B as ToOwned>::Owned>,
-{
-    fn dispatch<I: Iterator<Item = Self>>(iter: I) -> (C, D) {
-        let mut c = C::default();
-        let mut d = D::default();
-
-        for element in iter {
-            match element {
-                Cow::Borrowed(v) => c.extend(Some(v)),
-                Cow::Owned(v) => d.extend(Some(v)),
-            }
+implement_dispatch!(Poll<T>, Ready(T), Pending);
+
+implement_collect_trait! {
+    /// Allows to collect values from an iterator by dispatching `Ready` variants
+    /// and `Pending` variants in two different containers.
+    ///
+    /// As `Pending` holds no value, a `()` is added to the second container each
+    /// time it is met.
+    ///
+    /// ```
+    /// use std::task::Poll;
+    ///
+    /// use edisp_core::{containers::Count, prelude::*};
+    ///
+    /// let iter = vec![Poll::Pending, Poll::Ready(1), Poll::Pending].into_iter();
+    /// let (ready, pending): (Vec<_>, Count) = iter.dispatch_poll();
+    ///
+    /// assert_eq!(ready, [1]);
+    /// assert_eq!(pending.into_inner(), 2);
+    /// ```
+    CollectPoll<T> for Poll<T> {
+        fn dispatch_poll(C: T, D: ());
+        fn count_polls;
+    }
+}
+
+implement_dispatch!(ControlFlow<B, C>, Continue(C), Break(B));
+
+implement_collect_trait! {
+    /// Allows to collect values from an iterator by dispatching `Continue`
+    /// variants and `Break` variants in two different containers.
+    ///
+    /// ```
+    /// use std::ops::ControlFlow;
+    ///
+    /// use edisp_core::prelude::*;
+    ///
+    /// let iter = vec![
+    ///     ControlFlow::Continue(1),
+    ///     ControlFlow::Break("stop"),
+    ///     ControlFlow::Continue(2),
+    /// ]
+    /// .into_iter();
+    /// let (continues, breaks): (Vec<_>, Vec<_>) = iter.dispatch_control_flow();
+    ///
+    /// assert_eq!(continues, [1, 2]);
+    /// assert_eq!(breaks, ["stop"]);
+    /// ```
+    CollectControlFlow<B, C> for ControlFlow<B, C> {
+        fn dispatch_control_flow(D: C, E: B);
+        fn count_control_flows;
+    }
+}
+
+implement_dispatch!(Bound<T>, Included(T), Excluded(T), Unbounded);
+
+implement_collect_trait! {
+    /// Allows to collect values from an iterator by dispatching `Included`,
+    /// `Excluded` and `Unbounded` variants in three different containers.
+    ///
+    /// As `Unbounded` holds no value, a `()` is added to the third container each
+    /// time it is met.
+    ///
+    /// ```
+    /// use std::ops::Bound;
+    ///
+    /// use edisp_core::{containers::Count, prelude::*};
+    ///
+    /// let iter = vec![Bound::Included(1), Bound::Unbounded, Bound::Excluded(5)].into_iter();
+    /// let (included, excluded, unbounded): (Vec<_>, Vec<_>, Count) = iter.dispatch_bound();
+    ///
+    /// assert_eq!(included, [1]);
+    /// assert_eq!(excluded, [5]);
+    /// assert_eq!(unbounded.into_inner(), 1);
+    /// ```
+    CollectBound<T> for Bound<T> {
+        fn dispatch_bound(C: T, D: T, E: ());
+        fn count_bounds;
+    }
+}
+
+#[cfg(feature = "std")]
+implement_dispatch!(VarError, NotPresent, NotUnicode(OsString));
+
+#[cfg(feature = "std")]
+implement_collect_trait! {
+    /// Allows to collect environment variable errors from an iterator by
+    /// dispatching `NotPresent` variants and `NotUnicode` variants in two
+    /// different containers.
+    ///
+    /// As `NotPresent` holds no value, a `()` is added to the first container each
+    /// time it is met.
+    ///
+    /// ```
+    /// use std::env::{self, VarError};
+    ///
+    /// use edisp_core::{containers::Count, prelude::*};
+    ///
+    /// let iter = ["EDISP_SURELY_UNDEFINED_1", "EDISP_SURELY_UNDEFINED_2"]
+    ///     .iter()
+    ///     .filter_map(|name| env::var(name).err());
+    /// let (not_present, not_unicode): (Count, Vec<_>) = iter.dispatch_var_error();
+    ///
+    /// assert_eq!(not_present.into_inner(), 2);
+    /// assert!(not_unicode.is_empty());
+    /// ```
+    CollectVarError for VarError {
+        fn dispatch_var_error(C: (), D: OsString);
+        fn count_var_errors;
+    }
+}
+
+implement_dispatch!(Ordering, Less, Equal, Greater);
+
+implement_collect_trait! {
+    /// Allows to collect orderings from an iterator by dispatching `Less`,
+    /// `Equal` and `Greater` variants in three different containers.
+    ///
+    /// As no variant holds a value, a `()` is added to the corresponding container
+    /// each time one of them is met. `count_orderings` is usually what is needed.
+    ///
+    /// ```
+    /// use edisp_core::prelude::*;
+    ///
+    /// let values = [3, 1, 4, 1, 5];
+    /// let iter = values.windows(2).map(|pair| pair[0].cmp(&pair[1]));
+    /// let [less, equal, greater] = iter.count_orderings();
+    ///
+    /// assert_eq!(less, 2);
+    /// assert_eq!(equal, 0);
+    /// assert_eq!(greater, 2);
+    /// ```
+    CollectOrdering for Ordering {
+        fn dispatch_orderings(C: (), D: (), E: ());
+        fn count_orderings;
+
+        /// Counts `Less`, `Equal` and `Greater` variants.
+        ///
+        /// This is equivalent to `count_orderings`.
+        fn dispatch_ordering(self) -> [usize; 3] {
+            self.count_orderings()
         }
+    }
+}
+
+implement_dispatch!(FpCategory, Nan, Infinite, Zero, Subnormal, Normal);
 
-        (c, d)
+implement_collect_trait! {
+    /// Allows to collect floating point classifications from an iterator by
+    /// dispatching `Nan`, `Infinite`, `Zero`, `Subnormal` and `Normal` variants in
+    /// five different containers.
+    ///
+    /// As no variant holds a value, a `()` is added to the corresponding container
+    /// each time one of them is met. `count_fp_categories` is usually what is
+    /// needed.
+    ///
+    /// ```
+    /// use edisp_core::prelude::*;
+    ///
+    /// let values = [1.0, f64::NAN, 0.0, -0.0, f64::INFINITY, 2.5];
+    /// let [nans, infinites, zeros, subnormals, normals] =
+    ///     values.iter().map(|value| value.classify()).count_fp_categories();
+    ///
+    /// assert_eq!(nans, 1);
+    /// assert_eq!(infinites, 1);
+    /// assert_eq!(zeros, 2);
+    /// assert_eq!(subnormals, 0);
+    /// assert_eq!(normals, 2);
+    /// ```
+    CollectFpCategory for FpCategory {
+        fn dispatch_fp_category(C: (), D: (), E: (), F: (), G: ());
+        fn count_fp_categories;
+    }
+}
+
+implement_dispatch!(Alignment, Left, Right, Center);
+
+implement_collect_trait! {
+    /// Allows to collect formatting alignments from an iterator by dispatching
+    /// `Left`, `Right` and `Center` variants in three different containers.
+    ///
+    /// As no variant holds a value, a `()` is added to the corresponding container
+    /// each time one of them is met.
+    ///
+    /// ```
+    /// use std::fmt::Alignment;
+    ///
+    /// use edisp_core::prelude::*;
+    ///
+    /// let iter = vec![Alignment::Left, Alignment::Center, Alignment::Left].into_iter();
+    ///
+    /// assert_eq!(iter.count_alignments(), [2, 0, 1]);
+    /// ```
+    CollectAlignment for Alignment {
+        fn dispatch_alignment(C: (), D: (), E: ());
+        fn count_alignments;
+    }
+}
+
+impl<'a, B: 'a + ToOwned + ?Sized> Variants for Cow<'a, B> {
+    type Either = Either2<&'a B, <B as ToOwned>::Owned>;
+
+    fn into_either(self) -> Self::Either {
+        match self {
+            Cow::Borrowed(v) => Either2::V1(v),
+            Cow::Owned(v) => Either2::V2(v),
+        }
+    }
+
+    fn from_either(either: Self::Either) -> Self {
+        match either {
+            Either2::V1(v) => Cow::Borrowed(v),
+            Either2::V2(v) => Cow::Owned(v),
+        }
+    }
+}
+
+impl<'a, B: 'a + ToOwned + ?Sized> VariantKind for Cow<'a, B> {
+    const VARIANT_NAMES: &'static [&'static str] = &["Borrowed", "Owned"];
+
+    fn kind(&self) -> VariantIndex {
+        match self {
+            Cow::Borrowed(_) => VariantIndex::new(0),
+            Cow::Owned(_) => VariantIndex::new(1),
+        }
     }
 }
 
-/// Allows to collect owned values and borrowed values separately.
+implement_collect_trait! {
+    /// Allows to collect owned values and borrowed values separately.
+    ///
+    /// This may be usefull. The first value inside the tuple contains the borrowed
+    /// data while the second one contains the owned data.
+    CollectCow<'a, B> for Cow<'a, B>
+    where [B: 'a + ToOwned + ?Sized] {
+        fn dispatch_cow(C: &'a B, D: <B as ToOwned>::Owned);
+        fn count_cows;
+
+        /// Converts every value into owned data, and collects them in a
+        /// single container.
+        ///
+        /// The number of values which were borrowed, and therefore had to be
+        /// cloned, is returned alongside the container.
+        ///
+        /// ```
+        /// use std::borrow::Cow;
+        ///
+        /// use edisp_core::prelude::*;
+        ///
+        /// let iter = vec![Cow::Borrowed("foo"), Cow::Owned("bar".to_string())].into_iter();
+        /// let (owned, borrowed_count): (Vec<String>, _) = iter.dispatch_cow_into_owned();
+        ///
+        /// assert_eq!(owned, ["foo", "bar"]);
+        /// assert_eq!(borrowed_count, 1);
+        /// ```
+        fn dispatch_cow_into_owned<C>(self) -> (C, usize)
+        where
+            C: Default + Extend<<B as ToOwned>::Owned>,
+        {
+            let mut owned = C::default();
+            let mut borrowed_count = 0;
+
+            owned.extend(self.map(|value| {
+                if let Cow::Borrowed(_) = value {
+                    borrowed_count += 1;
+                }
+
+                value.into_owned()
+            }));
+
+            (owned, borrowed_count)
+        }
+    }
+}
+
+/// Allows to collect references to `Cow` values without consuming them.
 ///
-/// This may be usefull. The first value inside the tuple contains the borrowed
-/// data while the second one contains the owned data.
-pub trait CollectCow<'a, B>
+/// The first container receives the borrowed data, the second one receives
+/// references to the owned data.
+///
+/// ```
+/// use std::borrow::Cow;
+///
+/// use edisp_core::prelude::*;
+///
+/// let values = vec![Cow::Borrowed("foo"), Cow::Owned("bar".to_string())];
+/// let (borrowed, owned): (Vec<&str>, Vec<&String>) = values.iter().dispatch_cow_ref();
+///
+/// assert_eq!(borrowed, ["foo"]);
+/// assert_eq!(owned, ["bar"]);
+/// ```
+pub trait CollectCowRef<'a, 'b, B>
 where
     B: 'a + ToOwned + ?Sized,
+    'a: 'b,
 {
-    /// Collects values and dispatch them.
-    fn dispatch_cow<C, D>(self) -> (C, D)
+    /// Collects references to values and dispatch them.
+    fn dispatch_cow_ref<C, D>(self) -> (C, D)
     where
         C: Default + Extend<&'a B>,
-        D: Default + Extend<<B as ToOwned>::Owned>;
+        D: Default + Extend<&'b <B as ToOwned>::Owned>;
 }
 
-impl<'a, B, I> CollectCow<'a, B> for I
+impl<'a, 'b, B, I> CollectCowRef<'a, 'b, B> for I
 where
     B: 'a + ToOwned + ?Sized,
-    I: Iterator<Item = Cow<'a, B>>,
+    'a: 'b,
+    I: Iterator<Item = &'b Cow<'a, B>>,
 {
-    fn dispatch_cow<C, D>(self) -> (C, D)
+    fn dispatch_cow_ref<C, D>(self) -> (C, D)
     where
         C: Default + Extend<&'a B>,
-        D: Default + Extend<<B as ToOwned>::Owned>,
+        D: Default + Extend<&'b <B as ToOwned>::Owned>,
     {
-        Cow::dispatch(self)
+        Either2::dispatch(self.map(|value| match value {
+            Cow::Borrowed(borrowed) => Either2::V1(*borrowed),
+            Cow::Owned(owned) => Either2::V2(owned),
+        }))
     }
 }
 
@@ -107,6 +531,126 @@ mod tests {
         assert_eq!(some_errs, vec!["foo", "bar"],);
     }
 
+    #[test]
+    fn count_results_impl() {
+        let i = vec![Ok(42), Err("foo"), Ok(101)].into_iter();
+        let [oks, errs] = i.count_results();
+
+        assert_eq!(oks, 2);
+        assert_eq!(errs, 1);
+    }
+
+    #[test]
+    fn dispatch_result_collapse_impl() {
+        let i = vec![Ok(42), Ok(101)].into_iter();
+        let collapsed: Result<Vec<_>, (&str, _)> = i.dispatch_result_collapse();
+
+        assert_eq!(collapsed, Ok(vec![42, 101]));
+    }
+
+    #[test]
+    fn collect_option_impl() {
+        let i = vec![Some(42), None, Some(101), None].into_iter();
+        let (somes, nones): (Vec<_>, Vec<_>) = i.dispatch_option();
+
+        assert_eq!(somes, vec![42, 101]);
+        assert_eq!(nones, vec![(), ()]);
+    }
+
+    #[test]
+    fn count_options_impl() {
+        let [somes, nones] = vec![None, Some('a'), None].into_iter().count_options();
+
+        assert_eq!(somes, 1);
+        assert_eq!(nones, 2);
+    }
+
+    #[test]
+    fn collect_poll_impl() {
+        let i = vec![Poll::Ready('a'), Poll::Pending, Poll::Ready('b')].into_iter();
+        let (ready, pending): (String, Vec<_>) = i.dispatch_poll();
+
+        assert_eq!(ready, "ab");
+        assert_eq!(pending, vec![()]);
+    }
+
+    #[test]
+    fn count_control_flows_impl() {
+        let i = vec![
+            ControlFlow::Break(0),
+            ControlFlow::Continue(()),
+            ControlFlow::Break(1),
+        ];
+        let [continues, breaks] = i.into_iter().count_control_flows();
+
+        assert_eq!(continues, 1);
+        assert_eq!(breaks, 2);
+    }
+
+    #[test]
+    fn count_bounds_impl() {
+        let i = vec![Bound::Excluded(0), Bound::Excluded(1), Bound::Unbounded];
+        let [included, excluded, unbounded] = i.into_iter().count_bounds();
+
+        assert_eq!(included, 0);
+        assert_eq!(excluded, 2);
+        assert_eq!(unbounded, 1);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn collect_var_error_impl() {
+        let i = vec![
+            VarError::NotUnicode(OsString::from("foo")),
+            VarError::NotPresent,
+        ]
+        .into_iter();
+        let (not_present, not_unicode): (Vec<_>, Vec<_>) = i.dispatch_var_error();
+
+        assert_eq!(not_present, vec![()]);
+        assert_eq!(not_unicode, vec![OsString::from("foo")]);
+    }
+
+    #[test]
+    fn collect_ordering_impl() {
+        let i = vec![Ordering::Equal, Ordering::Greater, Ordering::Equal].into_iter();
+
+        assert_eq!(i.clone().dispatch_ordering(), [0, 2, 1]);
+
+        let (less, equal, greater): (Vec<_>, Vec<_>, Vec<_>) = i.dispatch_orderings();
+        assert_eq!((less.len(), equal.len(), greater.len()), (0, 2, 1));
+    }
+
+    #[test]
+    fn collect_fp_category_impl() {
+        let i = vec![FpCategory::Subnormal, FpCategory::Nan].into_iter();
+        let (nans, _, _, subnormals, normals): (Vec<_>, Vec<_>, Vec<_>, Vec<_>, Vec<_>) =
+            i.dispatch_fp_category();
+
+        assert_eq!(nans, vec![()]);
+        assert_eq!(subnormals, vec![()]);
+        assert!(normals.is_empty());
+    }
+
+    #[test]
+    fn collect_alignment_impl() {
+        let i = vec![Alignment::Right, Alignment::Right].into_iter();
+        let (left, right, center): (Vec<_>, Vec<_>, Vec<_>) = i.dispatch_alignment();
+
+        assert!(left.is_empty());
+        assert_eq!(right, vec![(), ()]);
+        assert!(center.is_empty());
+    }
+
+    #[test]
+    fn dispatch_cow_ref_impl() {
+        let values = [Cow::Owned(42), Cow::Borrowed(&-1), Cow::Owned(101)];
+        let (some_borrowed, some_owned): (Vec<&i8>, Vec<&i8>) = values.iter().dispatch_cow_ref();
+
+        assert_eq!(some_borrowed, vec![&-1]);
+        assert_eq!(some_owned, vec![&42, &101]);
+    }
+
     #[test]
     fn collect_cow_impl() {
         let i = vec![Cow::Owned(42), Cow::Borrowed(&-1), Cow::Owned(101)].into_iter();
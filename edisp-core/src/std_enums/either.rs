@@ -0,0 +1,55 @@
+//! Dispatching the `Either` enum of the `either` crate.
+//!
+//! This module is enabled by the `either` feature.
+
+use ::either::Either;
+
+use crate::prelude::*;
+
+implement_dispatch!(Either<L, R>, Left(L), Right(R));
+
+implement_collect_trait! {
+    /// Allows to collect values from an iterator by dispatching `Left`
+    /// variants and `Right` variants in two different containers.
+    ///
+    /// `Either` implements `Iterator` when both its sides do, which makes
+    /// `Either::dispatch` ambiguous in this case. This adapter is not.
+    ///
+    /// ```
+    /// use edisp_core::prelude::*;
+    /// use either::Either;
+    ///
+    /// let iter = vec![Either::Left(1), Either::Right("foo"), Either::Left(2)].into_iter();
+    /// let (lefts, rights): (Vec<_>, Vec<_>) = iter.dispatch_either();
+    ///
+    /// assert_eq!(lefts, [1, 2]);
+    /// assert_eq!(rights, ["foo"]);
+    /// ```
+    CollectEither<L, R> for Either<L, R> {
+        fn dispatch_either(C: L, D: R);
+        fn count_eithers;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn count_eithers_impl() {
+        let i = vec![Either::Left('a'), Either::Right(1), Either::Left('b')];
+        let [lefts, rights] = i.into_iter().count_eithers();
+
+        assert_eq!(lefts, 2);
+        assert_eq!(rights, 1);
+    }
+
+    #[test]
+    fn dispatch_either_impl() {
+        let i = vec![Either::Right(1), Either::Left('a'), Either::Right(2)];
+        let (lefts, rights): (String, Vec<_>) = i.into_iter().dispatch();
+
+        assert_eq!(lefts, "a");
+        assert_eq!(rights, [1, 2]);
+    }
+}
@@ -0,0 +1,103 @@
+//! Dispatching filesystem enums.
+//!
+//! This module is enabled by the `fs` feature.
+
+use std::{
+    ffi::OsStr,
+    io::SeekFrom,
+    path::{Component, PrefixComponent},
+};
+
+use crate::prelude::*;
+
+implement_dispatch!(SeekFrom, Start(u64), End(i64), Current(i64));
+
+implement_collect_trait! {
+    /// Allows to collect seek offsets from an iterator by dispatching `Start`,
+    /// `End` and `Current` variants in three different containers.
+    ///
+    /// ```
+    /// use std::io::SeekFrom;
+    ///
+    /// use edisp_core::prelude::*;
+    ///
+    /// let iter = vec![SeekFrom::Start(0), SeekFrom::Current(-4), SeekFrom::End(-1)].into_iter();
+    /// let (starts, ends, currents): (Vec<_>, Vec<_>, Vec<_>) = iter.dispatch_seek_from();
+    ///
+    /// assert_eq!(starts, [0]);
+    /// assert_eq!(ends, [-1]);
+    /// assert_eq!(currents, [-4]);
+    /// ```
+    CollectSeekFrom for SeekFrom {
+        fn dispatch_seek_from(C: u64, D: i64, E: i64);
+        fn count_seek_froms;
+    }
+}
+
+implement_dispatch!(
+    Component<'a>,
+    Prefix(PrefixComponent<'a>),
+    RootDir,
+    CurDir,
+    ParentDir,
+    Normal(&'a OsStr),
+);
+
+implement_collect_trait! {
+    /// Allows to collect path components from an iterator by dispatching
+    /// `Prefix`, `RootDir`, `CurDir`, `ParentDir` and `Normal` variants in five
+    /// different containers.
+    ///
+    /// As `RootDir`, `CurDir` and `ParentDir` hold no value, a `()` is added to
+    /// the corresponding container each time one of them is met.
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use edisp_core::{containers::Count, prelude::*};
+    ///
+    /// let path = Path::new("/srv/../etc/./passwd");
+    /// let (_, roots, _, parents, normals): (Vec<_>, Count, Vec<_>, Count, Vec<_>) =
+    ///     path.components().dispatch_component();
+    ///
+    /// assert_eq!(roots.into_inner(), 1);
+    /// assert_eq!(parents.into_inner(), 1);
+    /// assert_eq!(normals, ["srv", "etc", "passwd"]);
+    /// ```
+    CollectComponent<'a> for Component<'a> {
+        fn dispatch_component(C: PrefixComponent<'a>, D: (), E: (), F: (), G: &'a OsStr);
+        fn count_components;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn count_seek_froms_impl() {
+        let i = vec![
+            SeekFrom::Current(1),
+            SeekFrom::Current(2),
+            SeekFrom::Start(3),
+        ];
+        let [starts, ends, currents] = i.into_iter().count_seek_froms();
+
+        assert_eq!(starts, 1);
+        assert_eq!(ends, 0);
+        assert_eq!(currents, 2);
+    }
+
+    #[test]
+    fn count_components_impl() {
+        let path = std::path::Path::new("../../foo/./bar");
+        let [prefixes, roots, curdirs, parents, normals] = path.components().count_components();
+
+        assert_eq!(prefixes, 0);
+        assert_eq!(roots, 0);
+        // `Path::components` drops inner `.` components.
+        assert_eq!(curdirs, 0);
+        assert_eq!(parents, 2);
+        assert_eq!(normals, 2);
+    }
+}
@@ -0,0 +1,88 @@
+//! Dispatching networking enums.
+//!
+//! This module is enabled by the `net` feature.
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+use crate::prelude::*;
+
+implement_dispatch!(IpAddr, V4(Ipv4Addr), V6(Ipv6Addr));
+
+implement_collect_trait! {
+    /// Allows to collect IP addresses from an iterator by dispatching IPv4
+    /// addresses and IPv6 addresses in two different containers.
+    ///
+    /// ```
+    /// use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+    ///
+    /// use edisp_core::prelude::*;
+    ///
+    /// let iter = vec![
+    ///     IpAddr::V4(Ipv4Addr::LOCALHOST),
+    ///     IpAddr::V6(Ipv6Addr::LOCALHOST),
+    /// ]
+    /// .into_iter();
+    /// let (v4, v6): (Vec<_>, Vec<_>) = iter.dispatch_ip_addr();
+    ///
+    /// assert_eq!(v4, [Ipv4Addr::LOCALHOST]);
+    /// assert_eq!(v6, [Ipv6Addr::LOCALHOST]);
+    /// ```
+    CollectIpAddr for IpAddr {
+        fn dispatch_ip_addr(C: Ipv4Addr, D: Ipv6Addr);
+        fn count_ip_addrs;
+    }
+}
+
+implement_dispatch!(SocketAddr, V4(SocketAddrV4), V6(SocketAddrV6));
+
+implement_collect_trait! {
+    /// Allows to collect socket addresses from an iterator by dispatching IPv4
+    /// socket addresses and IPv6 socket addresses in two different containers.
+    ///
+    /// ```
+    /// use std::net::SocketAddr;
+    ///
+    /// use edisp_core::prelude::*;
+    ///
+    /// let iter = vec!["127.0.0.1:80", "[::1]:80", "0.0.0.0:8080"]
+    ///     .into_iter()
+    ///     .map(|addr| addr.parse::<SocketAddr>().unwrap());
+    /// let (v4, v6): (Vec<_>, Vec<_>) = iter.dispatch_socket_addr();
+    ///
+    /// assert_eq!(v4.len(), 2);
+    /// assert_eq!(v6[0].port(), 80);
+    /// ```
+    CollectSocketAddr for SocketAddr {
+        fn dispatch_socket_addr(C: SocketAddrV4, D: SocketAddrV6);
+        fn count_socket_addrs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn count_ip_addrs_impl() {
+        let i = vec![
+            IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+            IpAddr::V4(Ipv4Addr::BROADCAST),
+            IpAddr::V6(Ipv6Addr::UNSPECIFIED),
+        ];
+        let [v4, v6] = i.into_iter().count_ip_addrs();
+
+        assert_eq!(v4, 2);
+        assert_eq!(v6, 1);
+    }
+
+    #[test]
+    fn collect_socket_addr_impl() {
+        let v4 = SocketAddrV4::new(Ipv4Addr::LOCALHOST, 80);
+        let v6 = SocketAddrV6::new(Ipv6Addr::LOCALHOST, 443, 0, 0);
+        let i = vec![SocketAddr::V6(v6), SocketAddr::V4(v4)].into_iter();
+        let (some_v4, some_v6): (Vec<_>, Vec<_>) = i.dispatch_socket_addr();
+
+        assert_eq!(some_v4, vec![v4]);
+        assert_eq!(some_v6, vec![v6]);
+    }
+}
@@ -0,0 +1,106 @@
+//! Dispatching the `Value` enum of the `serde_json` crate.
+//!
+//! This module is enabled by the `serde_json` feature.
+
+use alloc::{string::String, vec::Vec};
+
+use ::serde_json::{Map, Number, Value};
+
+use crate::prelude::*;
+
+implement_dispatch!(
+    Value,
+    Null,
+    Bool(bool),
+    Number(Number),
+    String(String),
+    Array(Vec<Value>),
+    Object(Map<String, Value>),
+);
+
+implement_collect_trait! {
+    /// Allows to collect JSON values from an iterator by dispatching them in
+    /// one container per kind: nulls, booleans, numbers, strings, arrays and
+    /// objects.
+    ///
+    /// As `Null` holds no value, a `()` is added to the first container each
+    /// time it is met. Using `containers::Count` or `containers::Ignore` as
+    /// first container allows to count or skip them.
+    ///
+    /// ```
+    /// use edisp_core::{
+    ///     containers::{Count, Ignore},
+    ///     prelude::*,
+    /// };
+    /// use serde_json::{json, Value};
+    ///
+    /// let scraped = json!([1, "two", null, 3.5, { "four": 4 }, [5], true]);
+    ///
+    /// let (nulls, _, numbers, strings, _, objects): (
+    ///     Count,
+    ///     Ignore,
+    ///     Vec<_>,
+    ///     Vec<_>,
+    ///     Ignore,
+    ///     Vec<_>,
+    /// ) = match scraped {
+    ///     Value::Array(values) => values.into_iter().dispatch_json_value(),
+    ///     _ => unreachable!(),
+    /// };
+    ///
+    /// assert_eq!(nulls.into_inner(), 1);
+    /// assert_eq!(numbers[1].as_f64(), Some(3.5));
+    /// assert_eq!(strings, ["two"]);
+    /// assert_eq!(objects[0]["four"], 4);
+    /// ```
+    CollectJsonValue for Value {
+        fn dispatch_json_value(
+            C: (),
+            D: bool,
+            E: Number,
+            F: String,
+            G: Vec<Value>,
+            H: Map<String, Value>,
+        );
+        fn count_json_values;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::serde_json::json;
+
+    use super::*;
+    use crate::containers::Count;
+
+    #[test]
+    fn count_json_values_impl() {
+        let i = vec![json!(null), json!([]), json!({}), json!(null), json!(false)];
+        let [nulls, bools, numbers, strings, arrays, objects] = i.into_iter().count_json_values();
+
+        assert_eq!(
+            [nulls, bools, numbers, strings, arrays, objects],
+            [2, 1, 0, 0, 1, 1]
+        );
+    }
+
+    #[test]
+    fn collect_json_value_impl() {
+        let i = vec![json!("a"), json!(1), json!(true), json!([null]), json!("b")];
+        let (nulls, bools, numbers, strings, arrays, objects): (
+            Count,
+            Vec<_>,
+            Vec<_>,
+            Vec<_>,
+            Vec<_>,
+            Vec<_>,
+        ) = i.into_iter().dispatch_json_value();
+
+        assert_eq!(nulls.into_inner(), 0);
+        assert_eq!(bools, [true]);
+        assert_eq!(numbers, [Number::from(1)]);
+        assert_eq!(strings, ["a", "b"]);
+        assert_eq!(arrays, [vec![Value::Null]]);
+        assert!(objects.is_empty());
+    }
+}
@@ -0,0 +1,179 @@
+//! Dispatching synchronization enums.
+//!
+//! This module is enabled by the `sync` feature.
+
+use std::sync::{
+    mpsc::{RecvTimeoutError, TryRecvError, TrySendError},
+    PoisonError, TryLockError,
+};
+
+use crate::prelude::*;
+
+implement_dispatch!(TryLockError<T>, Poisoned(PoisonError<T>), WouldBlock);
+
+implement_collect_trait! {
+    /// Allows to collect lock errors from an iterator by dispatching `Poisoned`
+    /// variants and `WouldBlock` variants in two different containers.
+    ///
+    /// As `WouldBlock` holds no value, a `()` is added to the second container
+    /// each time it is met.
+    ///
+    /// ```
+    /// use std::sync::Mutex;
+    ///
+    /// use edisp_core::{containers::Count, prelude::*};
+    ///
+    /// let mutex = Mutex::new(0);
+    /// let guard = mutex.lock().unwrap();
+    ///
+    /// let iter = (0..3).filter_map(|_| mutex.try_lock().err());
+    /// let (poisoned, would_block): (Vec<_>, Count) = iter.dispatch_try_lock_error();
+    ///
+    /// assert!(poisoned.is_empty());
+    /// assert_eq!(would_block.into_inner(), 3);
+    /// # drop(guard);
+    /// ```
+    CollectTryLockError<T> for TryLockError<T> {
+        fn dispatch_try_lock_error(C: PoisonError<T>, D: ());
+        fn count_try_lock_errors;
+    }
+}
+
+implement_dispatch!(TryRecvError, Empty, Disconnected);
+
+implement_collect_trait! {
+    /// Allows to collect channel errors from an iterator by dispatching `Empty`
+    /// variants and `Disconnected` variants in two different containers.
+    ///
+    /// As neither variant holds a value, a `()` is added to the corresponding
+    /// container each time one of them is met. `count_try_recv_errors` is usually
+    /// what is needed.
+    ///
+    /// ```
+    /// use std::sync::mpsc;
+    ///
+    /// use edisp_core::prelude::*;
+    ///
+    /// let (sender, receiver) = mpsc::channel::<u8>();
+    /// let mut errors = vec![receiver.try_recv().unwrap_err()];
+    /// drop(sender);
+    /// errors.push(receiver.try_recv().unwrap_err());
+    ///
+    /// assert_eq!(errors.into_iter().count_try_recv_errors(), [1, 1]);
+    /// ```
+    CollectTryRecvError for TryRecvError {
+        fn dispatch_try_recv_error(C: (), D: ());
+        fn count_try_recv_errors;
+    }
+}
+
+implement_dispatch!(RecvTimeoutError, Timeout, Disconnected);
+
+implement_collect_trait! {
+    /// Allows to collect channel errors from an iterator by dispatching `Timeout`
+    /// variants and `Disconnected` variants in two different containers.
+    ///
+    /// This is the timeout-based counterpart of `CollectTryRecvError`.
+    ///
+    /// ```
+    /// use std::{sync::mpsc, time::Duration};
+    ///
+    /// use edisp_core::prelude::*;
+    ///
+    /// let (sender, receiver) = mpsc::channel::<u8>();
+    /// let timeout = Duration::from_millis(1);
+    /// let mut errors = vec![receiver.recv_timeout(timeout).unwrap_err()];
+    /// drop(sender);
+    /// errors.push(receiver.recv_timeout(timeout).unwrap_err());
+    ///
+    /// assert_eq!(errors.into_iter().count_recv_timeout_errors(), [1, 1]);
+    /// ```
+    CollectRecvTimeoutError for RecvTimeoutError {
+        fn dispatch_recv_timeout_error(C: (), D: ());
+        fn count_recv_timeout_errors;
+    }
+}
+
+implement_dispatch!(TrySendError<T>, Full(T), Disconnected(T));
+
+implement_collect_trait! {
+    /// Allows to collect rejected payloads from an iterator by dispatching `Full`
+    /// variants and `Disconnected` variants in two different containers.
+    ///
+    /// This allows to retry sending the payloads which were rejected because the
+    /// channel was full, while dropping the ones which can never be received.
+    ///
+    /// ```
+    /// use std::sync::mpsc;
+    ///
+    /// use edisp_core::prelude::*;
+    ///
+    /// let (sender, receiver) = mpsc::sync_channel(1);
+    /// let iter = (0..3).filter_map(|n| sender.try_send(n).err());
+    /// let (full, disconnected): (Vec<_>, Vec<_>) = iter.dispatch_try_send_error();
+    ///
+    /// assert_eq!(full, [1, 2]);
+    /// assert!(disconnected.is_empty());
+    /// # drop(receiver);
+    /// ```
+    CollectTrySendError<T> for TrySendError<T> {
+        fn dispatch_try_send_error(C: T, D: T);
+        fn count_try_send_errors;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collect_try_lock_error_impl() {
+        let i = vec![
+            TryLockError::WouldBlock,
+            TryLockError::Poisoned(PoisonError::new(42)),
+        ]
+        .into_iter();
+        let (poisoned, would_block): (Vec<_>, Vec<_>) = i.dispatch_try_lock_error();
+
+        assert_eq!(
+            poisoned
+                .into_iter()
+                .map(PoisonError::into_inner)
+                .collect::<Vec<_>>(),
+            vec![42]
+        );
+        assert_eq!(would_block, vec![()]);
+    }
+
+    #[test]
+    fn collect_try_recv_error_impl() {
+        let i = vec![TryRecvError::Empty, TryRecvError::Empty].into_iter();
+        let (empty, disconnected): (Vec<_>, Vec<_>) = i.dispatch_try_recv_error();
+
+        assert_eq!(empty, vec![(), ()]);
+        assert!(disconnected.is_empty());
+    }
+
+    #[test]
+    fn collect_recv_timeout_error_impl() {
+        let i = vec![RecvTimeoutError::Disconnected].into_iter();
+        let (timeout, disconnected): (Vec<_>, Vec<_>) = i.dispatch_recv_timeout_error();
+
+        assert!(timeout.is_empty());
+        assert_eq!(disconnected, vec![()]);
+    }
+
+    #[test]
+    fn collect_try_send_error_impl() {
+        let i = vec![
+            TrySendError::Disconnected('a'),
+            TrySendError::Full('b'),
+            TrySendError::Disconnected('c'),
+        ]
+        .into_iter();
+        let (full, disconnected): (String, String) = i.dispatch_try_send_error();
+
+        assert_eq!(full, "b");
+        assert_eq!(disconnected, "ac");
+    }
+}
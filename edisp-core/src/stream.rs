@@ -0,0 +1,115 @@
+//! Dispatching asynchronous streams.
+//!
+//! Services often receive their values from a `Stream` rather than from an
+//! iterator. Collecting the stream in a `Vec` before dispatching it would keep
+//! every value in memory. This module contains the `dispatch_stream` function,
+//! which dispatches values as the stream yields them, and shortcuts for the
+//! enums of `std`.
+//!
+//! This module is only available with the `futures` feature.
+
+use futures::stream::{Stream, StreamExt};
+
+use crate::dispatcher::DispatchInto;
+
+/// Dispatches the values yielded by a stream.
+///
+/// The generic type `O` should be a tuple whose arity is equal to the number
+/// of variants of `E`, and should contain only types which implement
+/// `Default` and `Extend`.
+///
+/// ```
+/// use edisp_core::prelude::*;
+/// use futures::{executor::block_on, stream};
+///
+/// let stream = stream::iter(vec![Either3::V1(1), Either3::V2("foo"), Either3::V3('a')]);
+/// let (ones, twos, threes): (Vec<_>, Vec<_>, Vec<_>) = block_on(dispatch_stream(stream));
+///
+/// assert_eq!(ones, [1]);
+/// assert_eq!(twos, ["foo"]);
+/// assert_eq!(threes, ['a']);
+/// ```
+pub async fn dispatch_stream<S, E, O>(stream: S) -> O
+where
+    S: Stream<Item = E>,
+    E: DispatchInto<O>,
+    O: Default,
+{
+    let mut containers = O::default();
+
+    futures::pin_mut!(stream);
+    while let Some(value) = stream.next().await {
+        value.dispatch_into(&mut containers);
+    }
+
+    containers
+}
+
+/// Dispatches a stream of `Result`s, the `Ok` values in `C` and the `Err`
+/// values in `D`.
+///
+/// ```
+/// use edisp_core::prelude::*;
+/// use futures::{executor::block_on, stream};
+///
+/// let stream = stream::iter(vec![Ok(1), Err("foo"), Ok(2)]);
+/// let (some_oks, some_errs): (Vec<_>, Vec<_>) = block_on(dispatch_result_stream(stream));
+///
+/// assert_eq!(some_oks, [1, 2]);
+/// assert_eq!(some_errs, ["foo"]);
+/// ```
+pub async fn dispatch_result_stream<S, T, E, C, D>(stream: S) -> (C, D)
+where
+    S: Stream<Item = Result<T, E>>,
+    C: Default + Extend<T>,
+    D: Default + Extend<E>,
+{
+    dispatch_stream(stream).await
+}
+
+/// Dispatches a stream of `Option`s, the `Some` values in `C` and the `None`
+/// values in `D`.
+///
+/// ```
+/// use edisp_core::{containers::Count, prelude::*};
+/// use futures::{executor::block_on, stream};
+///
+/// let stream = stream::iter(vec![Some(1), None, Some(2)]);
+/// let (somes, nones): (Vec<_>, Count) = block_on(dispatch_option_stream(stream));
+///
+/// assert_eq!(somes, [1, 2]);
+/// assert_eq!(nones.into_inner(), 1);
+/// ```
+pub async fn dispatch_option_stream<S, T, C, D>(stream: S) -> (C, D)
+where
+    S: Stream<Item = Option<T>>,
+    C: Default + Extend<T>,
+    D: Default + Extend<()>,
+{
+    dispatch_stream(stream).await
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{channel::mpsc, executor::block_on, join, SinkExt};
+
+    use super::*;
+
+    #[test]
+    fn dispatch_result_stream_from_channel() {
+        let (mut tx, rx) = mpsc::channel(1);
+
+        let send = async move {
+            for n in 0..10 {
+                let value = if n % 4 == 0 { Err(n) } else { Ok(n) };
+                tx.send(value).await.unwrap();
+            }
+        };
+
+        let ((), (some_oks, some_errs)): ((), (Vec<_>, Vec<_>)) =
+            block_on(async { join!(send, dispatch_result_stream(rx)) });
+
+        assert_eq!(some_oks, [1, 2, 3, 5, 6, 7, 9]);
+        assert_eq!(some_errs, [0, 4, 8]);
+    }
+}
@@ -0,0 +1,114 @@
+//! Dispatching a limited number of values.
+//!
+//! Sampling the first errors of a huge log does not require collecting every
+//! success. This module contains the `DispatchTake` trait, which limits the
+//! number of values each container receives.
+
+use crate::{dispatch::Variants, either::*};
+
+/// A dispatcher trait limiting the size of each container.
+///
+/// This trait behaves like `Dispatch`, except that each container receives at
+/// most as many values as its limit. Values exceeding their limit are
+/// dropped, and the iterator is no longer consumed once every limit is
+/// reached.
+///
+/// ```
+/// use edisp_core::prelude::*;
+///
+/// let mut iter = vec![Ok(1), Err("foo"), Ok(2), Ok(3), Err("bar"), Ok(4)].into_iter();
+/// let (some_oks, some_errs): (Vec<_>, Vec<_>) = Result::dispatch_take(iter.by_ref(), [1, 2]);
+///
+/// assert_eq!(some_oks, [1]);
+/// assert_eq!(some_errs, ["foo", "bar"]);
+/// assert_eq!(iter.next(), Some(Ok(4)));
+/// ```
+pub trait DispatchTake<O>
+where
+    Self: Variants,
+    Self::Either: EitherN,
+{
+    /// Performs dispatching, with a maximum number of values per container.
+    fn dispatch_take<I: IntoIterator<Item = Self>>(
+        iter: I,
+        limits: <Self::Either as EitherN>::Counts,
+    ) -> O;
+}
+
+/// Implements `DispatchTake` for every enum with a given number of variants.
+macro_rules! implement_dispatch_take_for_arity {
+    (
+        $either:ident,
+        $arity:literal,
+        $( (
+            $variant:ident,
+            $t:ident,
+            $c:ident,
+            $c_name:ident,
+            $f:ident,
+            $f_name:ident,
+            $idx:tt
+        ) ),+ $( , )?
+    ) => {
+        impl<E, $( $t, )+ $( $c, )+> DispatchTake<( $( $c, )+ )> for E
+        where
+            E: Variants<Either = $either< $( $t, )+ >>,
+            $( $c: Default + Extend<$t>, )+
+        {
+            fn dispatch_take<I: IntoIterator<Item = Self>>(
+                iter: I,
+                limits: [usize; $arity],
+            ) -> ( $( $c, )+ ) {
+                $( let mut $c_name = $c::default(); )+
+                let mut iter = iter.into_iter();
+                let mut counts = [0; $arity];
+
+                while counts != limits {
+                    let element = match iter.next() {
+                        Some(element) => element,
+                        None => break,
+                    };
+
+                    match element.into_either() {
+                        $(
+                            $either::$variant(value) => {
+                                if counts[$idx] < limits[$idx] {
+                                    counts[$idx] += 1;
+                                    $c_name.extend(Some(value));
+                                }
+                            }
+                        )+
+                    }
+                }
+
+                ( $( $c_name, )+ )
+            }
+        }
+    };
+}
+
+for_each_arity!(implement_dispatch_take_for_arity);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dispatch_take_drops_overflow() {
+        let i = vec![Ok(1), Ok(2), Ok(3), Err("foo")].into_iter();
+        let (some_oks, some_errs): (Vec<_>, Vec<_>) = Result::dispatch_take(i, [2, 10]);
+
+        assert_eq!(some_oks, [1, 2]);
+        assert_eq!(some_errs, ["foo"]);
+    }
+
+    #[test]
+    fn dispatch_take_zero_limits() {
+        let mut i = vec![Ok::<_, ()>(1)].into_iter();
+        let (some_oks, some_errs): (Vec<_>, Vec<_>) = Result::dispatch_take(i.by_ref(), [0, 0]);
+
+        assert!(some_oks.is_empty());
+        assert!(some_errs.is_empty());
+        assert_eq!(i.next(), Some(Ok(1)));
+    }
+}
@@ -0,0 +1,325 @@
+//! Fallible dispatching.
+//!
+//! Some containers can not accept every value they are given: fixed-capacity
+//! buffers may be full, channels may be disconnected. This module contains
+//! the `TryExtend` trait, which describes such containers, the `TryDispatch`
+//! trait, which dispatches values in them and stops at the first failure, and
+//! the `TryDispatchInto` trait, which does the same for a single value and
+//! existing containers.
+
+use alloc::collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::convert::Infallible;
+#[cfg(feature = "std")]
+use core::hash::{BuildHasher, Hash};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+#[cfg(feature = "std")]
+use std::sync::mpsc::{SendError, Sender, SyncSender};
+
+use crate::{dispatch::Variants, either::*};
+
+/// A container which may fail to accept values.
+///
+/// This is the fallible counterpart of `Extend`. It is implemented for every
+/// growable collection of `std`, which never fail, and for channel senders,
+/// which fail once their receiver is disconnected.
+pub trait TryExtend<A> {
+    /// The error returned when a value can not be added to the container.
+    type Error;
+
+    /// Extends the container with the content of an iterator.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error as soon as a value can not be added to
+    /// the container. Values yielded by the iterator after the failing one
+    /// are not consumed.
+    fn try_extend<I: IntoIterator<Item = A>>(&mut self, iter: I) -> Result<(), Self::Error>;
+}
+
+/// Implements `TryExtend` for a collection which already implements `Extend`.
+macro_rules! implement_infallible_try_extend {
+    ( $( [ $( $generics:tt )* ] $item:ty => $collection:ty ),+ $( , )? ) => {
+        $(
+            impl< $( $generics )* > TryExtend<$item> for $collection {
+                type Error = Infallible;
+
+                fn try_extend<I: IntoIterator<Item = $item>>(
+                    &mut self,
+                    iter: I,
+                ) -> Result<(), Infallible> {
+                    self.extend(iter);
+                    Ok(())
+                }
+            }
+        )+
+    };
+}
+
+implement_infallible_try_extend! {
+    [T] T => Vec<T>,
+    [T] T => VecDeque<T>,
+    [T] T => LinkedList<T>,
+    [T: Ord] T => BinaryHeap<T>,
+    [T: Ord] T => BTreeSet<T>,
+    [K: Ord, V] (K, V) => BTreeMap<K, V>,
+    [] char => String,
+    ['a] &'a str => String,
+    [] String => String,
+}
+
+#[cfg(feature = "std")]
+implement_infallible_try_extend! {
+    [T: Eq + Hash, S: BuildHasher] T => HashSet<T, S>,
+    [K: Eq + Hash, V, S: BuildHasher] (K, V) => HashMap<K, V, S>,
+}
+
+/// Implements `TryExtend` for the sending half of a channel.
+#[cfg(feature = "std")]
+macro_rules! implement_sender_try_extend {
+    ( $( $sender:ident ),+ $( , )? ) => {
+        $(
+            impl<T> TryExtend<T> for $sender<T> {
+                type Error = SendError<T>;
+
+                fn try_extend<I: IntoIterator<Item = T>>(
+                    &mut self,
+                    iter: I,
+                ) -> Result<(), SendError<T>> {
+                    for value in iter {
+                        self.send(value)?;
+                    }
+
+                    Ok(())
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "std")]
+implement_sender_try_extend!(Sender, SyncSender);
+
+/// A fallible dispatcher trait.
+///
+/// This trait is the fallible counterpart of `Dispatch`. The generic type `O`
+/// should be a tuple whose arity is equal to the number of variants of the
+/// implementor, and should contain only types which implement `Default` and
+/// `TryExtend`.
+///
+/// Dispatching stops at the first value a container refuses. The returned
+/// error is an `EitherN` indicating which container failed, and why.
+///
+/// ```
+/// use edisp_core::prelude::*;
+///
+/// let iter = vec![Ok(1), Err("foo"), Ok(2)].into_iter();
+/// let dispatched: Result<(Vec<_>, Vec<_>), _> = Result::try_dispatch(iter);
+///
+/// let (some_oks, some_errs) = dispatched.unwrap();
+/// assert_eq!(some_oks, [1, 2]);
+/// assert_eq!(some_errs, ["foo"]);
+/// ```
+pub trait TryDispatch<O>
+where
+    Self: Sized,
+{
+    /// The error returned when a value can not be added to its container.
+    type Error;
+
+    /// Performs fallible dispatching.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error as soon as a container refuses a value.
+    /// The iterator is not consumed any further.
+    fn try_dispatch<I: IntoIterator<Item = Self>>(iter: I) -> Result<O, Self::Error>;
+}
+
+/// Implements `TryDispatch` for every enum with a given number of variants.
+macro_rules! implement_try_dispatch_for_arity {
+    (
+        $either:ident,
+        $arity:literal,
+        $( (
+            $variant:ident,
+            $t:ident,
+            $c:ident,
+            $c_name:ident,
+            $f:ident,
+            $f_name:ident,
+            $idx:tt
+        ) ),+ $( , )?
+    ) => {
+        impl<E, $( $t, )+ $( $c, )+> TryDispatch<( $( $c, )+ )> for E
+        where
+            E: Variants<Either = $either< $( $t, )+ >>,
+            $( $c: Default + TryExtend<$t>, )+
+        {
+            type Error = $either< $( $c::Error, )+ >;
+
+            fn try_dispatch<I: IntoIterator<Item = Self>>(
+                iter: I,
+            ) -> Result<( $( $c, )+ ), Self::Error> {
+                $( let mut $c_name = $c::default(); )+
+
+                for element in iter {
+                    match element.into_either() {
+                        $(
+                            $either::$variant(value) => $c_name
+                                .try_extend(Some(value))
+                                .map_err($either::$variant)?,
+                        )+
+                    }
+                }
+
+                Ok(( $( $c_name, )+ ))
+            }
+        }
+    };
+}
+
+for_each_arity!(implement_try_dispatch_for_arity);
+
+/// Dispatches a single value in existing fallible containers.
+///
+/// This is the fallible counterpart of `DispatchInto`. It does not require
+/// containers to implement `Default`, which allows for instance to dispatch
+/// values to the channels of worker threads.
+///
+#[cfg_attr(feature = "std", doc = "```")]
+#[cfg_attr(not(feature = "std"), doc = "```ignore")]
+/// use std::sync::mpsc;
+/// use std::thread;
+///
+/// use edisp_core::prelude::*;
+///
+/// let (ok_tx, ok_rx) = mpsc::channel();
+/// let (err_tx, err_rx) = mpsc::channel();
+/// let worker = thread::spawn(move || ok_rx.iter().sum::<i32>());
+///
+/// let mut senders = (ok_tx, err_tx);
+/// for value in vec![Ok(1), Err("foo"), Ok(2)] {
+///     value.try_dispatch_into(&mut senders).unwrap();
+/// }
+/// drop(senders);
+///
+/// assert_eq!(worker.join().unwrap(), 3);
+/// assert_eq!(err_rx.iter().collect::<Vec<_>>(), ["foo"]);
+/// ```
+pub trait TryDispatchInto<O> {
+    /// The error returned when the value can not be added to its container.
+    type Error;
+
+    /// Adds the value in the container matching its variant.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the container refuses the value.
+    fn try_dispatch_into(self, containers: &mut O) -> Result<(), Self::Error>;
+}
+
+/// Implements `TryDispatchInto` for every enum with a given number of
+/// variants.
+macro_rules! implement_try_dispatch_into_for_arity {
+    (
+        $either:ident,
+        $arity:literal,
+        $( (
+            $variant:ident,
+            $t:ident,
+            $c:ident,
+            $c_name:ident,
+            $f:ident,
+            $f_name:ident,
+            $idx:tt
+        ) ),+ $( , )?
+    ) => {
+        impl<E, $( $t, )+ $( $c, )+> TryDispatchInto<( $( $c, )+ )> for E
+        where
+            E: Variants<Either = $either< $( $t, )+ >>,
+            $( $c: TryExtend<$t>, )+
+        {
+            type Error = $either< $( $c::Error, )+ >;
+
+            fn try_dispatch_into(
+                self,
+                containers: &mut ( $( $c, )+ ),
+            ) -> Result<(), Self::Error> {
+                match self.into_either() {
+                    $(
+                        $either::$variant(value) => containers
+                            .$idx
+                            .try_extend(Some(value))
+                            .map_err($either::$variant),
+                    )+
+                }
+            }
+        }
+    };
+}
+
+for_each_arity!(implement_try_dispatch_into_for_arity);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A container which refuses values once it holds two of them.
+    #[derive(Debug, Default, PartialEq)]
+    struct Pair<T>(Vec<T>);
+
+    impl<T> TryExtend<T> for Pair<T> {
+        type Error = T;
+
+        fn try_extend<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<(), T> {
+            for value in iter {
+                if self.0.len() == 2 {
+                    return Err(value);
+                }
+                self.0.push(value);
+            }
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn try_dispatch_success() {
+        let i = vec![Ok(42), Err("foo"), Ok(101)].into_iter();
+        let (some_oks, some_errs): (Pair<_>, Vec<_>) = Result::try_dispatch(i).unwrap();
+
+        assert_eq!(some_oks, Pair(vec![42, 101]));
+        assert_eq!(some_errs, ["foo"]);
+    }
+
+    #[test]
+    fn try_dispatch_stops_at_first_failure() {
+        let mut i = vec![Ok(1), Ok(2), Err("foo"), Ok(3), Ok(4)].into_iter();
+        let dispatched: Result<(Pair<_>, Vec<_>), _> = Result::try_dispatch(i.by_ref());
+
+        assert_eq!(dispatched.unwrap_err(), Either2::V1(3));
+        assert_eq!(i.next(), Some(Ok(4)));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn try_dispatch_into_disconnected_sender() {
+        let (ok_tx, ok_rx) = std::sync::mpsc::channel();
+        let (err_tx, err_rx) = std::sync::mpsc::sync_channel(1);
+        let mut senders = (ok_tx, err_tx);
+
+        Ok::<_, char>(1).try_dispatch_into(&mut senders).unwrap();
+        drop(ok_rx);
+
+        let error = Ok::<_, char>(2)
+            .try_dispatch_into(&mut senders)
+            .unwrap_err();
+        assert_eq!(error, Either2::V1(SendError(2)));
+
+        Err::<i32, _>('a').try_dispatch_into(&mut senders).unwrap();
+        assert_eq!(err_rx.recv(), Ok('a'));
+    }
+}
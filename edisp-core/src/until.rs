@@ -0,0 +1,131 @@
+//! Dispatching until a condition is met.
+//!
+//! Unbounded sources, such as sockets or log streams, can not be dispatched
+//! entirely. This module contains the `DispatchUntil` trait, which stops
+//! dispatching as soon as a predicate holds, and gives back what remains of
+//! the iterator.
+
+use core::iter::Chain;
+use core::option;
+
+use crate::{dispatch::Variants, either::*};
+
+/// The values which have not been dispatched by `DispatchUntil`.
+///
+/// It yields the value on which the predicate held, if any, then every value
+/// the original iterator has not yielded yet.
+pub type Remaining<I> = Chain<option::IntoIter<<I as Iterator>::Item>, I>;
+
+/// A dispatcher trait stopping when a predicate holds.
+///
+/// Before each value is dispatched, the predicate is called with this value
+/// and the number of values dispatched so far in each container. Dispatching
+/// stops as soon as it returns `true`. The containers are then returned with
+/// the remaining values, starting with the one which stopped dispatching.
+///
+/// ```
+/// use edisp_core::prelude::*;
+///
+/// let iter = vec![Ok(1), Err("foo"), Ok(2), Err("bar"), Ok(3)].into_iter();
+/// let ((some_oks, some_errs), remaining): ((Vec<_>, Vec<_>), _) =
+///     Result::dispatch_until(iter, |_, [_, errs]| *errs == 2);
+///
+/// assert_eq!(some_oks, [1, 2]);
+/// assert_eq!(some_errs, ["foo", "bar"]);
+/// assert_eq!(remaining.collect::<Vec<_>>(), [Ok(3)]);
+/// ```
+pub trait DispatchUntil<O>
+where
+    Self: Variants,
+    Self::Either: EitherN,
+{
+    /// Performs dispatching until `stop` returns `true`.
+    fn dispatch_until<I, P>(iter: I, stop: P) -> (O, Remaining<I::IntoIter>)
+    where
+        I: IntoIterator<Item = Self>,
+        P: FnMut(&Self, &<Self::Either as EitherN>::Counts) -> bool;
+}
+
+/// Implements `DispatchUntil` for every enum with a given number of variants.
+macro_rules! implement_dispatch_until_for_arity {
+    (
+        $either:ident,
+        $arity:literal,
+        $( (
+            $variant:ident,
+            $t:ident,
+            $c:ident,
+            $c_name:ident,
+            $f:ident,
+            $f_name:ident,
+            $idx:tt
+        ) ),+ $( , )?
+    ) => {
+        impl<E, $( $t, )+ $( $c, )+> DispatchUntil<( $( $c, )+ )> for E
+        where
+            E: Variants<Either = $either< $( $t, )+ >>,
+            $( $c: Default + Extend<$t>, )+
+        {
+            fn dispatch_until<I, P>(
+                iter: I,
+                mut stop: P,
+            ) -> (( $( $c, )+ ), Remaining<I::IntoIter>)
+            where
+                I: IntoIterator<Item = Self>,
+                P: FnMut(&Self, &[usize; $arity]) -> bool,
+            {
+                $( let mut $c_name = $c::default(); )+
+                let mut counts = [0; $arity];
+                let mut iter = iter.into_iter();
+                let mut stopped_on = None;
+
+                for element in iter.by_ref() {
+                    if stop(&element, &counts) {
+                        stopped_on = Some(element);
+                        break;
+                    }
+
+                    match element.into_either() {
+                        $(
+                            $either::$variant(value) => {
+                                counts[$idx] += 1;
+                                $c_name.extend(Some(value));
+                            }
+                        )+
+                    }
+                }
+
+                (( $( $c_name, )+ ), stopped_on.into_iter().chain(iter))
+            }
+        }
+    };
+}
+
+for_each_arity!(implement_dispatch_until_for_arity);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dispatch_until_keeps_stopping_value() {
+        let i = vec![Ok(1), Ok(2), Err("stop"), Ok(3)].into_iter();
+        let ((some_oks, some_errs), remaining): ((Vec<_>, Vec<_>), _) =
+            Result::dispatch_until(i, |element, _| element.is_err());
+
+        assert_eq!(some_oks, [1, 2]);
+        assert!(some_errs.is_empty());
+        assert_eq!(remaining.collect::<Vec<_>>(), [Err("stop"), Ok(3)]);
+    }
+
+    #[test]
+    fn dispatch_until_exhausted() {
+        let i = vec![Ok(1), Err("foo")].into_iter();
+        let ((some_oks, some_errs), mut remaining): ((Vec<_>, Vec<_>), _) =
+            Result::dispatch_until(i, |_, _| false);
+
+        assert_eq!(some_oks, [1]);
+        assert_eq!(some_errs, ["foo"]);
+        assert_eq!(remaining.next(), None);
+    }
+}
@@ -0,0 +1,155 @@
+//! Dispatching in successive batches.
+//!
+//! Long-running jobs may need to report progress or to forward partial
+//! results downstream before the whole input is consumed. This module
+//! contains the `DispatchWindows` trait, which turns an iterator into an
+//! iterator of containers, each of them holding a window of the input.
+
+use core::marker::PhantomData;
+
+use crate::dispatcher::DispatchInto;
+
+/// An iterator over successive windows of dispatched values.
+///
+/// It is returned by the methods of `DispatchWindows`. Each call to `next`
+/// dispatches values in fresh containers, until the window is full, and
+/// yields them. The last window may be smaller.
+#[derive(Debug)]
+pub struct Windows<I, O, F> {
+    iter: I,
+    size: usize,
+    flush: F,
+    marker: PhantomData<fn() -> O>,
+}
+
+impl<I, O, F> Iterator for Windows<I, O, F>
+where
+    I: Iterator,
+    I::Item: DispatchInto<O>,
+    O: Default,
+    F: FnMut(&O) -> bool,
+{
+    type Item = O;
+
+    fn next(&mut self) -> Option<O> {
+        let mut containers = O::default();
+        let mut len = 0;
+
+        for element in self.iter.by_ref() {
+            element.dispatch_into(&mut containers);
+            len += 1;
+
+            if len == self.size || (self.flush)(&containers) {
+                break;
+            }
+        }
+
+        if len == 0 {
+            None
+        } else {
+            Some(containers)
+        }
+    }
+}
+
+/// A dispatcher trait yielding containers periodically.
+///
+/// The generic type `O` should be a tuple whose arity is equal to the number
+/// of variants of the implementor, and should contain only types which
+/// implement `Default` and `Extend`.
+///
+/// ```
+/// use edisp_core::prelude::*;
+///
+/// let iter = vec![Ok(1), Err("foo"), Ok(2), Ok(3), Err("bar")].into_iter();
+/// let mut windows = Result::dispatch_windows(iter, 2);
+///
+/// let (some_oks, some_errs): (Vec<_>, Vec<_>) = windows.next().unwrap();
+/// assert_eq!(some_oks, [1]);
+/// assert_eq!(some_errs, ["foo"]);
+///
+/// assert_eq!(windows.next(), Some((vec![2, 3], vec![])));
+/// assert_eq!(windows.next(), Some((vec![], vec!["bar"])));
+/// assert_eq!(windows.next(), None);
+/// ```
+pub trait DispatchWindows<O>
+where
+    Self: Sized,
+{
+    /// Dispatches values in windows of `size` values.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `size` is zero.
+    fn dispatch_windows<I: IntoIterator<Item = Self>>(
+        iter: I,
+        size: usize,
+    ) -> Windows<I::IntoIter, O, fn(&O) -> bool>;
+
+    /// Dispatches values in windows, a new window being started each time
+    /// `flush` returns `true`.
+    ///
+    /// `flush` is called with the containers of the current window after
+    /// each value is dispatched.
+    fn dispatch_windows_by<I, F>(iter: I, flush: F) -> Windows<I::IntoIter, O, F>
+    where
+        I: IntoIterator<Item = Self>,
+        F: FnMut(&O) -> bool;
+}
+
+impl<E, O> DispatchWindows<O> for E
+where
+    E: DispatchInto<O>,
+    O: Default,
+{
+    fn dispatch_windows<I: IntoIterator<Item = Self>>(
+        iter: I,
+        size: usize,
+    ) -> Windows<I::IntoIter, O, fn(&O) -> bool> {
+        assert!(size != 0, "window size must be non-zero");
+
+        Windows {
+            iter: iter.into_iter(),
+            size,
+            flush: |_| false,
+            marker: PhantomData,
+        }
+    }
+
+    fn dispatch_windows_by<I, F>(iter: I, flush: F) -> Windows<I::IntoIter, O, F>
+    where
+        I: IntoIterator<Item = Self>,
+        F: FnMut(&O) -> bool,
+    {
+        Windows {
+            iter: iter.into_iter(),
+            size: usize::MAX,
+            flush,
+            marker: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::containers::Count;
+
+    #[test]
+    fn dispatch_windows_by_flushes_on_errors() {
+        let i = vec![Ok(1), Ok(2), Err('a'), Err('b'), Ok(3), Err('c'), Ok(4)];
+        let windows =
+            Result::dispatch_windows_by(i, |(_, errs): &(Vec<_>, Count)| errs.into_inner() == 2)
+                .map(|(oks, errs)| (oks, errs.into_inner()))
+                .collect::<Vec<_>>();
+
+        assert_eq!(windows, [(vec![1, 2], 2), (vec![3, 4], 1)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn dispatch_windows_zero_size() {
+        let _: Windows<_, (Vec<u8>, Vec<u8>), _> =
+            Result::dispatch_windows(Vec::<Result<u8, u8>>::new(), 0);
+    }
+}
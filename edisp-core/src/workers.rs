@@ -0,0 +1,157 @@
+//! Dispatching to worker threads.
+//!
+//! Each variant may need a different, and possibly slow, processing. This
+//! module contains the `DispatchToWorkers` trait, which spawns a thread for
+//! each variant, sends it the values of its variant through a channel, and
+//! collects what each thread returns.
+//!
+//! Values can also be sent to threads which already exist, by dispatching
+//! them in their channel senders with `TryDispatchInto`.
+
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+
+use crate::{dispatch::Variants, either::*};
+
+/// A dispatcher trait sending values to worker threads.
+///
+/// The generic type `W` should be a tuple whose arity is equal to the number
+/// of variants of the implementor. Each of its elements is a closure, which
+/// is run on its own scoped thread and receives the values of the
+/// corresponding variant through a `Receiver`. Values are dispatched on the
+/// current thread. Once the iterator is exhausted, the channels are closed and
+/// the value returned by each closure is collected.
+///
+/// A worker may return before its channel is closed. The values of its
+/// variant which are dispatched afterwards are dropped.
+///
+/// # Panics
+///
+/// If a worker panics, the panic is propagated once every worker has
+/// returned.
+///
+/// ```
+/// use edisp_core::prelude::*;
+///
+/// let iter = vec![Ok(1), Err("foo"), Ok(2), Err("bar")].into_iter();
+/// let (sum, errors) = Result::dispatch_to_workers(
+///     iter,
+///     (
+///         |values: std::sync::mpsc::Receiver<i32>| values.iter().sum::<i32>(),
+///         |errors: std::sync::mpsc::Receiver<&str>| errors.iter().collect::<Vec<_>>().join(", "),
+///     ),
+/// );
+///
+/// assert_eq!(sum, 3);
+/// assert_eq!(errors, "foo, bar");
+/// ```
+pub trait DispatchToWorkers<W>
+where
+    Self: Sized,
+{
+    /// The value returned by each worker.
+    type Output;
+
+    /// Performs dispatching, and waits for every worker.
+    fn dispatch_to_workers<I: IntoIterator<Item = Self>>(iter: I, workers: W) -> Self::Output;
+}
+
+/// Implements `DispatchToWorkers` for every enum with a given number of
+/// variants.
+macro_rules! implement_dispatch_to_workers_for_arity {
+    (
+        $either:ident,
+        $arity:literal,
+        $( (
+            $variant:ident,
+            $t:ident,
+            $c:ident,
+            $c_name:ident,
+            $f:ident,
+            $f_name:ident,
+            $idx:tt
+        ) ),+ $( , )?
+    ) => {
+        impl<E, $( $t, )+ $( $c, )+ $( $f, )+> DispatchToWorkers<( $( $f, )+ )> for E
+        where
+            E: Variants<Either = $either< $( $t, )+ >>,
+            $(
+                $t: Send,
+                $c: Send,
+                $f: FnOnce(Receiver<$t>) -> $c + Send,
+            )+
+        {
+            type Output = ( $( $c, )+ );
+
+            fn dispatch_to_workers<I: IntoIterator<Item = Self>>(
+                iter: I,
+                workers: ( $( $f, )+ ),
+            ) -> Self::Output {
+                let ( $( $f_name, )+ ) = workers;
+
+                thread::scope(|scope| {
+                    $( let $c_name = mpsc::channel::<$t>(); )+
+                    let senders = ( $( $c_name.0, )+ );
+
+                    $(
+                        let receiver = $c_name.1;
+                        let $c_name = scope.spawn(move || $f_name(receiver));
+                    )+
+
+                    for element in iter {
+                        // A closed channel means that its worker does not
+                        // need more values.
+                        match element.into_either() {
+                            $(
+                                $either::$variant(value) => {
+                                    let _ = senders.$idx.send(value);
+                                }
+                            )+
+                        }
+                    }
+
+                    drop(senders);
+
+                    $( let $c_name = $c_name.join(); )+
+                    ( $(
+                        match $c_name {
+                            Ok(output) => output,
+                            Err(payload) => std::panic::resume_unwind(payload),
+                        },
+                    )+ )
+                })
+            }
+        }
+    };
+}
+
+for_each_arity!(implement_dispatch_to_workers_for_arity);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn dispatch_to_workers_early_return() {
+        let i = (0..100).map(|n| {
+            if n % 2 == 0 {
+                Either3::V1(n)
+            } else {
+                Either3::V2(n)
+            }
+        });
+        let (first, odds, thirds) = Either3::dispatch_to_workers(
+            i,
+            (
+                |evens: Receiver<i32>| evens.recv().ok(),
+                |odds: Receiver<i32>| odds.iter().count(),
+                |thirds: Receiver<()>| thirds.iter().count(),
+            ),
+        );
+
+        assert_eq!(first, Some(0));
+        assert_eq!(odds, 50);
+        assert_eq!(thirds, 0);
+    }
+}
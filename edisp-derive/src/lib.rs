@@ -18,6 +18,11 @@ use proc_macro2::TokenStream as TokenStream2;
 ///   - the type on which `Dispatch` is derived is a struct or an union,
 ///   - one of the enum variants contains an anonymous structure.
 ///
+/// For enums with two to eight variants, the generated code implements the
+/// `Variants` trait, which provides `Dispatch` and every other dispatching
+/// flavor, and the `VariantKind` trait. For other enums, only `Dispatch` is
+/// implemented.
+///
 /// Note that a variant has no associated data, then empty tuples (`()`) will
 /// be added to the corresponding container each time this variant is met.
 #[proc_macro_derive(Dispatch)]
@@ -31,36 +36,97 @@ pub fn dispatch_macro_derive(input: TokenStream) -> TokenStream {
 
 fn impl_dispatch_macro(ast: DeriveInput) -> Result<TokenStream2> {
     let e = Enum::from_syn(ast)?;
+
+    if e.has_either_type() {
+        Ok(impl_variants(&e))
+    } else {
+        Ok(impl_dispatch(&e))
+    }
+}
+
+/// Implements `Variants` and `VariantKind` for an enum which has an `EitherN`
+/// counterpart.
+fn impl_variants(e: &Enum) -> TokenStream2 {
     let name = &e.name;
 
     let full_type = e.full_type();
+    let generics = e.generics_iter().collect::<Vec<_>>();
+
+    let either_type = e.either_type();
+    let inner_types = e.variants.iter().map(Variant::inner_type);
+
+    let into_arms = e
+        .variants
+        .iter()
+        .map(|v| v.match_arm_to_either(&either_type));
+    let from_arms = e
+        .variants
+        .iter()
+        .map(|v| v.match_arm_from_either(&either_type));
+    let variant_names = e.variants.iter().map(|v| v.inner.ident.to_string());
+    let kind_arms = e.variants.iter().map(Variant::match_arm_kind);
+
+    quote! {
+        impl< #( #generics, )* > Variants for #full_type {
+            type Either = #either_type < #( #inner_types, )* >;
+
+            fn into_either(self) -> Self::Either {
+                use #name ::*;
 
-    let ctn = e.container_type_name_iter();
-    let where_clause_content_iter = e.container_constraints_iter();
+                match self {
+                    #( #into_arms )*
+                }
+            }
 
-    let return_type = e.return_type();
-    let return_type2 = return_type.clone();
+            fn from_either(either: Self::Either) -> Self {
+                use #name ::*;
 
-    let container_names = e.variants.iter().map(|v| &v.container_name);
-    let containers_initialization = quote! {
-        #( let mut #container_names = #ctn::default(); )*
-    };
+                match either {
+                    #( #from_arms )*
+                }
+            }
+        }
 
-    let match_arms = e.variants.iter().map(Variant::match_arm);
+        impl< #( #generics, )* > VariantKind for #full_type {
+            const VARIANT_NAMES: &'static [&'static str] = &[ #( #variant_names, )* ];
 
-    let return_expression = e.return_expression();
+            fn kind(&self) -> VariantIndex {
+                use #name ::*;
 
-    let trait_generics = e.required_generics();
+                match self {
+                    #( #kind_arms )*
+                }
+            }
+        }
+    }
+}
 
-    Ok(quote! {
-        impl< #trait_generics > Dispatch< #return_type > for #full_type
-        where #( #where_clause_content_iter )*
+/// Implements `Dispatch` directly for an enum which has no `EitherN`
+/// counterpart.
+fn impl_dispatch(e: &Enum) -> TokenStream2 {
+    let name = &e.name;
+    let full_type = e.full_type();
+    let generics = e.generics_iter();
+
+    let container_types = e.variants.iter().map(Variant::container_type_name);
+    let container_types2 = container_types.clone();
+    let container_types3 = container_types.clone();
+    let container_types4 = container_types.clone();
+    let container_types5 = container_types.clone();
+    let container_names = e.variants.iter().map(Variant::container_name);
+    let container_names2 = container_names.clone();
+    let inner_types = e.variants.iter().map(Variant::inner_type);
+    let match_arms = e.variants.iter().map(Variant::match_arm_extend);
+
+    quote! {
+        impl< #( #generics, )* #( #container_types, )* > Dispatch<( #( #container_types2, )* )> for #full_type
+        where #( #container_types3: Default + Extend< #inner_types >, )*
         {
-            fn dispatch<I>(iter: I) -> #return_type2
+            fn dispatch<I>(iter: I) -> ( #( #container_types4, )* )
             where
-                I: Iterator<Item = #full_type >
+                I: IntoIterator<Item = Self>
             {
-                #containers_initialization
+                #( let mut #container_names = #container_types5::default(); )*
 
                 use #name ::*;
 
@@ -70,10 +136,10 @@ fn impl_dispatch_macro(ast: DeriveInput) -> Result<TokenStream2> {
                     }
                 }
 
-                #return_expression
+                ( #( #container_names2, )* )
             }
         }
-    })
+    }
 }
 
 /// An enum.
@@ -148,63 +214,14 @@ impl Enum {
         self.generics.params.iter()
     }
 
-    /// Returns an iterator over every container types the enum requires.
-    ///
-    /// For every enum variant corresponds a container type. In order to stay
-    /// general and not restrict the user, generic parameters are used.
-    fn container_type_name_iter(&self) -> impl Iterator<Item = &Ident> {
-        self.variants.iter().map(|v| &v.container_type_name)
+    /// Returns whether an `EitherN` type has as many variants as the enum.
+    fn has_either_type(&self) -> bool {
+        (MIN_EITHER_ARITY..=MAX_EITHER_ARITY).contains(&self.variants.len())
     }
 
-    /// Returns every generics which must be declared for the `Dispatch` trait.
-    fn required_generics(&self) -> TokenStream2 {
-        let generics = self.required_generics_iter();
-        quote! { #( #generics, )* }
-    }
-
-    /// Returns an iterator over every generics that have to be declared while
-    /// implementing `Dispatch`.
-    fn required_generics_iter(&self) -> impl Iterator<Item = TokenStream2> + '_ {
-        let enum_generics = self.generics_iter().map(|g| quote! { #g });
-        let dispatch_generics = self.container_type_name_iter().map(|g| quote! { #g });
-        enum_generics.chain(dispatch_generics)
-    }
-
-    /// Returns the expected return type for the `Dispatch` trait.
-    ///
-    /// The return type is a tuple of n elements (n being the amount of
-    /// enum variants). Each element has a name as defined in
-    /// `Enum::container_type_name_iter`.
-    fn return_type(&self) -> TokenStream2 {
-        let dispatch_generics = self.container_type_name_iter();
-        quote! { ( #( #dispatch_generics, )* ) }
-    }
-
-    /// Returns an iterator over every container type constraint.
-    fn container_constraints_iter(&self) -> impl Iterator<Item = TokenStream2> + '_ {
-        self.container_type_name_iter()
-            .zip(self.container_inner_type_iter())
-            .map(|(container_name, container_inner_type)| {
-                quote! { #container_name : Default + Extend< #container_inner_type >, }
-            })
-    }
-
-    /// Returns an iterator over the type each variant holds.
-    fn container_inner_type_iter(&self) -> impl Iterator<Item = TokenStream2> + '_ {
-        self.variants.iter().map(Variant::container_inner_type)
-    }
-
-    /// Returns the return expression expected in the `Dispatch`
-    /// implementation.
-    fn return_expression(&self) -> TokenStream2 {
-        let names = self.container_name_iter();
-        quote! { ( #( #names , )* ) }
-    }
-
-    /// Returns an iterator over every container name required to implement
-    /// `Dispatch`.
-    fn container_name_iter(&self) -> impl Iterator<Item = &Ident> {
-        self.variants.iter().map(|v| &v.container_name)
+    /// Returns the `EitherN` type which has as many variants as the enum.
+    fn either_type(&self) -> Ident {
+        format_ident!("Either{}", self.variants.len())
     }
 }
 
@@ -212,10 +229,10 @@ impl Enum {
 struct Variant {
     /// The variant parsed by `syn`.
     inner: SVariant,
-    /// The name of the associated type parameter.
-    container_type_name: Ident,
-    /// The name of the associted container.
-    container_name: Ident,
+    /// The name of the associated `EitherN` variant.
+    either_variant: Ident,
+    /// The zero-based index of the variant.
+    index: usize,
 }
 
 impl Variant {
@@ -236,22 +253,21 @@ impl Variant {
             ));
         }
 
-        let container_type_name = container_type_letter(idx);
-        let container_name = container_name(idx);
+        let either_variant = either_variant_name(idx);
         let inner = sv;
 
         Ok(Variant {
             inner,
-            container_type_name,
-            container_name,
+            either_variant,
+            index: idx,
         })
     }
 
-    /// Returns the inner type of the associated container.
+    /// Returns the type the variant holds.
     ///
     /// If the variant is an unit variant, then the returned token stream is
     /// `()`, otherwise, it is the contained type.
-    fn container_inner_type(&self) -> TokenStream2 {
+    fn inner_type(&self) -> TokenStream2 {
         match self.inner.fields {
             Fields::Unnamed(ref f) => {
                 let t = &f.unnamed;
@@ -262,10 +278,41 @@ impl Variant {
         }
     }
 
-    /// Returns the content of the enum match arm.
-    fn match_arm(&self) -> TokenStream2 {
+    /// Returns the match arm converting the variant into its `EitherN`
+    /// counterpart.
+    fn match_arm_to_either(&self, either_type: &Ident) -> TokenStream2 {
         let variant_name = &self.inner.ident;
-        let container_name = &self.container_name;
+        let either_variant = &self.either_variant;
+        match self.inner.fields {
+            Fields::Unnamed(_) => {
+                quote! {
+                    #variant_name (v) => #either_type :: #either_variant (v),
+                }
+            }
+            Fields::Unit => {
+                quote! {
+                    #variant_name => #either_type :: #either_variant (()),
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns the name of the type parameter of the associated container.
+    fn container_type_name(&self) -> Ident {
+        format_ident!("T{}", self.index)
+    }
+
+    /// Returns the name of the associated container.
+    fn container_name(&self) -> Ident {
+        format_ident!("c{}", self.index)
+    }
+
+    /// Returns the match arm adding the value of the variant to its
+    /// container.
+    fn match_arm_extend(&self) -> TokenStream2 {
+        let variant_name = &self.inner.ident;
+        let container_name = self.container_name();
         match self.inner.fields {
             Fields::Unnamed(_) => {
                 quote! {
@@ -280,6 +327,45 @@ impl Variant {
             _ => unreachable!(),
         }
     }
+
+    /// Returns the match arm returning the `VariantIndex` of the variant.
+    fn match_arm_kind(&self) -> TokenStream2 {
+        let variant_name = &self.inner.ident;
+        let index = self.index;
+        match self.inner.fields {
+            Fields::Unnamed(_) => {
+                quote! {
+                    #variant_name (..) => VariantIndex::new(#index),
+                }
+            }
+            Fields::Unit => {
+                quote! {
+                    #variant_name => VariantIndex::new(#index),
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns the match arm converting the `EitherN` counterpart of the
+    /// variant back into the variant.
+    fn match_arm_from_either(&self, either_type: &Ident) -> TokenStream2 {
+        let variant_name = &self.inner.ident;
+        let either_variant = &self.either_variant;
+        match self.inner.fields {
+            Fields::Unnamed(_) => {
+                quote! {
+                    #either_type :: #either_variant (v) => #variant_name (v),
+                }
+            }
+            Fields::Unit => {
+                quote! {
+                    #either_type :: #either_variant (()) => #variant_name,
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
 }
 
 /// Generates a *friendly* error message when `Dispatch` is derived on an enum
@@ -301,16 +387,13 @@ fn wrong_variant_field(
     )
 }
 
-/// Returns a custom type letter.
-///
-/// This type letter allows to declare generic containers.
-fn container_type_letter(n: usize) -> Ident {
-    format_ident!("T{}", n)
-}
+/// The number of variants of the smallest `EitherN` type.
+const MIN_EITHER_ARITY: usize = 2;
 
-/// Returns a custom container name.
-///
-/// This container name allows to declare variables in the `dispatch` method.
-fn container_name(n: usize) -> Ident {
-    format_ident!("c{}", n)
+/// The number of variants of the largest `EitherN` type.
+const MAX_EITHER_ARITY: usize = 8;
+
+/// Returns the name of the `n`-th variant of an `EitherN` type.
+fn either_variant_name(n: usize) -> Ident {
+    format_ident!("V{}", n + 1)
 }
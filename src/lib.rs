@@ -86,6 +86,11 @@
 //! The custom derive feature can be disabled by disabling `default-features`
 //! in the cargo manifest.
 //!
+//! Disabling `default-features` also disables the `std` feature, which makes
+//! this crate `no_std`. Dispatching then only requires `alloc`, and the
+//! dispatchers relying on `HashMap`, threads, channels or I/O are not
+//! available.
+//!
 //! ## Using declarative macro
 //!
 //! This crate provides a macro entitled `implement_dispatch`. It allows to
@@ -133,10 +138,13 @@
 //! [`Default`]: https://doc.rust-lang.org/std/default/trait.Default.html
 //! [`Extend`]: https://doc.rust-lang.org/std/iter/trait.Extend.html
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![forbid(missing_docs)]
 
 pub mod prelude;
 
+pub use edisp_core::containers;
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
@@ -205,4 +213,86 @@ mod tests {
         assert_eq!(some_a, [&42, &101]);
         assert_eq!(some_b, ['c']);
     }
+
+    #[test]
+    fn derive_round_trip() {
+        #[derive(Debug, Dispatch, PartialEq)]
+        enum E {
+            A(u8),
+            B,
+        }
+
+        use std::collections::BTreeMap;
+        use E::*;
+
+        let i = vec![B, A(42), B].into_iter();
+        let groups: BTreeMap<_, Vec<_>> = E::dispatch_by_kind(i);
+        assert_eq!(groups[&VariantIndex::new(0)], [A(42)]);
+        assert_eq!(groups[&VariantIndex::new(1)], [B, B]);
+    }
+
+    #[test]
+    fn derive_variant_kind() {
+        #[derive(Dispatch)]
+        enum E<T> {
+            Value(T),
+            Missing,
+            Invalid(String),
+        }
+
+        assert_eq!(E::<u8>::VARIANT_COUNT, 3);
+        assert_eq!(E::<u8>::VARIANT_NAMES, ["Value", "Missing", "Invalid"]);
+        assert_eq!(E::Value(42).kind().get(), 0);
+        assert_eq!(E::<u8>::Missing.variant_name(), "Missing");
+    }
+
+    #[test]
+    fn derive_single_variant() {
+        #[derive(Dispatch)]
+        enum E {
+            A(u8),
+        }
+
+        let (some_a,): (Vec<_>,) = E::dispatch(vec![E::A(1), E::A(2)]);
+        assert_eq!(some_a, [1, 2]);
+    }
+
+    #[test]
+    fn derive_nine_variants() {
+        use crate::containers::Count;
+
+        #[derive(Dispatch)]
+        enum Big {
+            A(u8),
+            B,
+            C,
+            D,
+            E,
+            F,
+            G,
+            H,
+            I(char),
+        }
+
+        let i = vec![Big::I('a'), Big::A(1), Big::B, Big::C, Big::D, Big::E];
+        let i = i
+            .into_iter()
+            .chain(vec![Big::F, Big::G, Big::H, Big::I('b')]);
+        let (some_a, b, c, d, e, f, g, h, some_i): (
+            Vec<_>,
+            Count,
+            Count,
+            Count,
+            Count,
+            Count,
+            Count,
+            Count,
+            String,
+        ) = Big::dispatch(i);
+
+        assert_eq!(some_a, [1]);
+        let units = [b, c, d, e, f, g, h].map(Count::into_inner);
+        assert_eq!(units, [1; 7]);
+        assert_eq!(some_i, "ab");
+    }
 }
@@ -1,4 +1,5 @@
-//! The prelude file imports various `CollectDispatch*` traits.
+//! The prelude file imports the dispatcher traits, the `EitherN` types and
+//! the `Dispatch` derive macro.
 //!
 //! This allows one
 //! to import everything just by writing `use edisp::prelude::*;`, and get